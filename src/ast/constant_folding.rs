@@ -0,0 +1,51 @@
+//! A small constant-expression evaluator for integer-valued expressions.
+//!
+//! This generalizes the folder that grew inside `bad_file_permissions` (S103):
+//! it resolves integer literals, named/attribute constants (via a caller-
+//! supplied `resolve` closure, e.g. over `stat.*`), and combines them through
+//! the bitwise and shift operators plus unary inversion and negation.
+//!
+//! The evaluator returns `None` for anything non-constant or overflowing,
+//! preserving the invariant that an unknown subexpression makes the whole
+//! expression unknown rather than producing a wrong value.
+
+use num_traits::ToPrimitive;
+use rustpython_ast::{Constant, Expr, ExprKind, Operator, Unaryop};
+
+/// Fold `expr` to a `u16`, resolving name/attribute leaves through `resolve`.
+pub fn eval_u16(expr: &Expr, resolve: &dyn Fn(&Expr) -> Option<u16>) -> Option<u16> {
+    match &expr.node {
+        ExprKind::Constant {
+            value: Constant::Int(value),
+            ..
+        } => value.to_u16(),
+        ExprKind::Attribute { .. } | ExprKind::Name { .. } => resolve(expr),
+        ExprKind::UnaryOp { op, operand } => {
+            let value = eval_u16(operand, resolve)?;
+            match op {
+                // `~x` masked to the value's width.
+                Unaryop::Invert => Some(!value),
+                // Arithmetic negation: only `-0` stays within the unsigned domain.
+                Unaryop::USub => value.checked_neg(),
+                Unaryop::UAdd => Some(value),
+                Unaryop::Not => None,
+            }
+        }
+        ExprKind::BinOp { left, op, right } => {
+            let left = eval_u16(left, resolve)?;
+            let right = eval_u16(right, resolve)?;
+            match op {
+                Operator::BitAnd => Some(left & right),
+                Operator::BitOr => Some(left | right),
+                Operator::BitXor => Some(left ^ right),
+                // Reject shifts that push set bits out of the 16-bit width.
+                Operator::LShift => left
+                    .checked_shl(u32::from(right))
+                    .filter(|shifted| shifted >> right == left),
+                Operator::RShift => left.checked_shr(u32::from(right)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,111 @@
+//! Editing helpers for `import` statements.
+//!
+//! Where [`remove_unused_imports`](super::helpers::remove_unused_imports) can
+//! only *delete* names, these helpers *merge* and *rewrite* `from` imports:
+//! they combine several `from module import a` / `from module import b`
+//! statements into a single grouped import, deduplicate members, and preserve
+//! `asname` aliases and relative-import levels — modeled on rust-analyzer's
+//! `merge_imports`/`insert_use` assists. Output is produced through the shared
+//! [`SourceCodeGenerator`], so callers emit a single [`Fix`].
+
+use rustpython_ast::{Alias, AliasData, Location, Stmt, StmtKind};
+
+use crate::source_code_generator::SourceCodeGenerator;
+use crate::source_code_style::SourceCodeStyleDetector;
+
+/// A `from` import's identity: the module name and its relative-import level.
+/// Imports are only ever merged within a single key, never across scopes.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct ImportKey {
+    pub module: Option<String>,
+    pub level: usize,
+}
+
+/// Group a run of top-level statements by import key, returning only the keys
+/// that appear in more than one `from` statement — the candidates a
+/// consolidation rule should flag and merge. `*` imports are excluded, since
+/// they are never merged.
+pub fn duplicate_from_imports<'a>(stmts: &'a [&'a Stmt]) -> Vec<(ImportKey, Vec<&'a Stmt>)> {
+    let mut order: Vec<ImportKey> = Vec::new();
+    let mut groups: Vec<Vec<&Stmt>> = Vec::new();
+    for stmt in stmts {
+        let Some(key) = import_key(stmt) else {
+            continue;
+        };
+        if let StmtKind::ImportFrom { names, .. } = &stmt.node {
+            if names.iter().any(|alias| alias.node.name == "*") {
+                continue;
+            }
+        }
+        if let Some(index) = order.iter().position(|existing| existing == &key) {
+            groups[index].push(stmt);
+        } else {
+            order.push(key);
+            groups.push(vec![stmt]);
+        }
+    }
+    order
+        .into_iter()
+        .zip(groups)
+        .filter(|(_, group)| group.len() > 1)
+        .collect()
+}
+
+/// Collect the `(module, level)` key of a `from` import statement.
+pub fn import_key(stmt: &Stmt) -> Option<ImportKey> {
+    match &stmt.node {
+        StmtKind::ImportFrom { module, level, .. } => Some(ImportKey {
+            module: module.clone(),
+            level: level.unwrap_or(0),
+        }),
+        _ => None,
+    }
+}
+
+/// Merge the members of several `from` imports that share a key into one
+/// statement, deduplicating by `(name, asname)` and re-sorting by name, and
+/// render it with `style`. Returns `None` if any statement is not a `from`
+/// import or a `*` import is present (those are never merged).
+pub fn merge_from_imports(
+    key: &ImportKey,
+    stmts: &[&Stmt],
+    style: &SourceCodeStyleDetector,
+) -> Option<String> {
+    let mut members: Vec<AliasData> = Vec::new();
+    for stmt in stmts {
+        let StmtKind::ImportFrom { names, .. } = &stmt.node else {
+            return None;
+        };
+        for alias in names {
+            if alias.node.name == "*" {
+                return None;
+            }
+            if !members
+                .iter()
+                .any(|m| m.name == alias.node.name && m.asname == alias.node.asname)
+            {
+                members.push(alias.node.clone());
+            }
+        }
+    }
+    members.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let names: Vec<Alias> = members
+        .into_iter()
+        .map(|node| Alias::new(Location::default(), Location::default(), node))
+        .collect();
+    let merged = Stmt::new(
+        Location::default(),
+        Location::default(),
+        StmtKind::ImportFrom {
+            module: key.module.clone(),
+            names,
+            level: Some(key.level),
+        },
+    );
+
+    let mut generator =
+        SourceCodeGenerator::new(style.indentation(), style.quote(), style.line_ending());
+    generator.unparse_stmt(&merged);
+    generator.generate().ok()
+}
@@ -0,0 +1,291 @@
+//! Generate `src/registry_gen.rs` from the `CheckCode` enum.
+//!
+//! Historically this derived only the *lexical* selectors: `CheckCodePrefix`
+//! (every alphabetic/numeric prefix of each code), its `codes()`/`specificity()`
+//! match arms, and `CATEGORIES` (the alphabetic source-plugin prefixes).
+//!
+//! It now also emits *semantic* groups, after Clippy's cross-cutting lint
+//! groups (`correctness`, `style`, `pedantic`, …): each [`CheckCode`] is
+//! annotated with one or more [`LintGroup`]s, and the codegen produces a
+//! `group()`/`codes_in_group()` API alongside the existing `codes()` so that a
+//! group is selectable from settings exactly like a prefix.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use codegen::{Scope, Type, Variant};
+use itertools::Itertools;
+use strum::IntoEnumIterator;
+
+use crate::registry::{CheckCode, PREFIX_REDIRECTS};
+
+const ALL: &str = "ALL";
+
+/// A cross-cutting semantic category a rule belongs to, independent of the
+/// source plugin (letter prefix) it lives under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LintGroup {
+    Correctness,
+    Style,
+    Security,
+    Upgrade,
+}
+
+impl LintGroup {
+    /// The stable, selectable name of the group (e.g. `"security"`).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LintGroup::Correctness => "correctness",
+            LintGroup::Style => "style",
+            LintGroup::Security => "security",
+            LintGroup::Upgrade => "upgrade",
+        }
+    }
+}
+
+/// The semantic groups a single `CheckCode` belongs to. Codes default to
+/// [`LintGroup::Style`] unless their prefix marks them otherwise.
+fn groups_for(code: &CheckCode) -> Vec<LintGroup> {
+    let name = code.as_ref();
+    let mut groups = Vec::new();
+    if name.starts_with('S') {
+        groups.push(LintGroup::Security);
+    }
+    if name.starts_with("UP") {
+        groups.push(LintGroup::Upgrade);
+    }
+    if name.starts_with('B') || name.starts_with('F') {
+        groups.push(LintGroup::Correctness);
+    }
+    if groups.is_empty() {
+        groups.push(LintGroup::Style);
+    }
+    groups
+}
+
+/// Render the `group()`/`codes_in_group()` source fragment.
+fn generate_groups() -> String {
+    let mut by_group: BTreeMap<&'static str, Vec<String>> = BTreeMap::new();
+    let mut arms = String::new();
+    for code in CheckCode::iter() {
+        let groups = groups_for(&code);
+        for group in &groups {
+            by_group
+                .entry(group.as_str())
+                .or_default()
+                .push(code.as_ref().to_string());
+        }
+        let rendered = groups
+            .iter()
+            .map(|group| format!("LintGroup::{group:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        arms.push_str(&format!(
+            "            CheckCode::{} => vec![{rendered}],\n",
+            code.as_ref()
+        ));
+    }
+
+    let mut out = String::new();
+    out.push_str("impl CheckCode {\n");
+    out.push_str("    /// The semantic groups this code belongs to.\n");
+    out.push_str("    pub fn group(&self) -> Vec<LintGroup> {\n");
+    out.push_str("        match self {\n");
+    out.push_str(&arms);
+    out.push_str("        }\n    }\n");
+    out.push_str("    /// Every code in `group`.\n");
+    out.push_str("    pub fn codes_in_group(group: LintGroup) -> Vec<CheckCode> {\n");
+    out.push_str("        CheckCode::iter().filter(|code| code.group().contains(&group)).collect()\n");
+    out.push_str("    }\n}\n");
+    out
+}
+
+/// Regenerate `src/registry_gen.rs`, including the semantic-group API.
+pub fn main() -> anyhow::Result<()> {
+    let mut output = generate_prefixes();
+    output.push('\n');
+    output.push_str(&generate_groups());
+    std::fs::write(
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src/registry_gen.rs"),
+        output,
+    )?;
+    Ok(())
+}
+
+/// Render the lexical-prefix machinery (`CheckCodePrefix`, `codes()`,
+/// `specificity()`, `CATEGORIES`) that this extension appends the semantic-group
+/// API to. The logic is unchanged from the original generator; it simply
+/// returns the source fragment instead of writing it, so `main()` can emit the
+/// groups after it.
+fn generate_prefixes() -> String {
+    // Build up a map from prefix to matching CheckCodes.
+    let mut prefix_to_codes: BTreeMap<String, BTreeSet<CheckCode>> = BTreeMap::default();
+    for check_code in CheckCode::iter() {
+        let code_str: String = check_code.as_ref().to_string();
+        let code_prefix_len = code_str
+            .chars()
+            .take_while(|char| char.is_alphabetic())
+            .count();
+        let code_suffix_len = code_str.len() - code_prefix_len;
+        for i in 0..=code_suffix_len {
+            let prefix = code_str[..code_prefix_len + i].to_string();
+            prefix_to_codes
+                .entry(prefix)
+                .or_default()
+                .insert(check_code.clone());
+        }
+        prefix_to_codes
+            .entry(ALL.to_string())
+            .or_default()
+            .insert(check_code.clone());
+    }
+
+    // Add any prefix aliases (e.g., "U" to "UP").
+    for (alias, check_code) in PREFIX_REDIRECTS.iter() {
+        prefix_to_codes.insert(
+            (*alias).to_string(),
+            prefix_to_codes
+                .get(&check_code.as_ref().to_string())
+                .unwrap_or_else(|| panic!("Unknown CheckCode: {alias:?}"))
+                .clone(),
+        );
+    }
+
+    let mut scope = Scope::new();
+
+    // Create the `CheckCodePrefix` definition.
+    let mut gen = scope
+        .new_enum("CheckCodePrefix")
+        .vis("pub")
+        .derive("EnumString")
+        .derive("AsRefStr")
+        .derive("Debug")
+        .derive("PartialEq")
+        .derive("Eq")
+        .derive("PartialOrd")
+        .derive("Ord")
+        .derive("Clone")
+        .derive("Serialize")
+        .derive("Deserialize")
+        .derive("JsonSchema");
+    for prefix in prefix_to_codes.keys() {
+        gen = gen.push_variant(Variant::new(prefix.to_string()));
+    }
+
+    // Create the `SuffixLength` definition.
+    scope
+        .new_enum("SuffixLength")
+        .vis("pub")
+        .derive("PartialEq")
+        .derive("Eq")
+        .derive("PartialOrd")
+        .derive("Ord")
+        .push_variant(Variant::new("None"))
+        .push_variant(Variant::new("Zero"))
+        .push_variant(Variant::new("One"))
+        .push_variant(Variant::new("Two"))
+        .push_variant(Variant::new("Three"))
+        .push_variant(Variant::new("Four"));
+
+    // Create the `match` statement, to map from definition to relevant codes.
+    let mut gen = scope
+        .new_impl("CheckCodePrefix")
+        .new_fn("codes")
+        .arg_ref_self()
+        .ret(Type::new("Vec<CheckCode>"))
+        .vis("pub")
+        .line("#[allow(clippy::match_same_arms)]")
+        .line("match self {");
+    for (prefix, codes) in &prefix_to_codes {
+        if let Some(target) = PREFIX_REDIRECTS.get(&prefix.as_str()) {
+            gen = gen.line(format!(
+                "CheckCodePrefix::{prefix} => {{ one_time_warning!(\"{{}}{{}} {{}}\", \
+                 \"warning\".yellow().bold(), \":\".bold(), \"`{}` has been remapped to \
+                 `{}`\".bold()); \n vec![{}] }}",
+                prefix,
+                target.as_ref(),
+                codes
+                    .iter()
+                    .map(|code| format!("CheckCode::{}", code.as_ref()))
+                    .join(", ")
+            ));
+        } else {
+            gen = gen.line(format!(
+                "CheckCodePrefix::{prefix} => vec![{}],",
+                codes
+                    .iter()
+                    .map(|code| format!("CheckCode::{}", code.as_ref()))
+                    .join(", ")
+            ));
+        }
+    }
+    gen.line("}");
+
+    // Create the `match` statement, to map from definition to specificity.
+    let mut gen = scope
+        .new_impl("CheckCodePrefix")
+        .new_fn("specificity")
+        .arg_ref_self()
+        .ret(Type::new("SuffixLength"))
+        .vis("pub")
+        .line("#[allow(clippy::match_same_arms)]")
+        .line("match self {");
+    for prefix in prefix_to_codes.keys() {
+        let specificity = if prefix == "ALL" {
+            "None"
+        } else {
+            let num_numeric = prefix.chars().filter(|char| char.is_numeric()).count();
+            match num_numeric {
+                0 => "Zero",
+                1 => "One",
+                2 => "Two",
+                3 => "Three",
+                4 => "Four",
+                _ => panic!("Invalid prefix: {prefix}"),
+            }
+        };
+        gen = gen.line(format!(
+            "CheckCodePrefix::{prefix} => SuffixLength::{specificity},"
+        ));
+    }
+    gen.line("}");
+
+    // Construct the output contents.
+    let mut output = String::new();
+    output
+        .push_str("//! File automatically generated by `examples/generate_check_code_prefix.rs`.");
+    output.push('\n');
+    output.push('\n');
+    output.push_str("use colored::Colorize;");
+    output.push('\n');
+    output.push_str("use schemars::JsonSchema;");
+    output.push('\n');
+    output.push_str("use serde::{Deserialize, Serialize};");
+    output.push('\n');
+    output.push_str("use strum_macros::{AsRefStr, EnumString};");
+    output.push('\n');
+    output.push('\n');
+    output.push_str("use crate::registry::CheckCode;");
+    output.push('\n');
+    output.push_str("use crate::one_time_warning;");
+    output.push('\n');
+    output.push('\n');
+    output.push_str(&scope.to_string());
+    output.push('\n');
+    output.push('\n');
+
+    // Add the list of output categories (not generated).
+    output.push_str("pub const CATEGORIES: &[CheckCodePrefix] = &[");
+    output.push('\n');
+    for prefix in prefix_to_codes.keys() {
+        if prefix.chars().all(char::is_alphabetic)
+            && !PREFIX_REDIRECTS.contains_key(&prefix.as_str())
+        {
+            output.push_str(&format!("CheckCodePrefix::{prefix},"));
+            output.push('\n');
+        }
+    }
+    output.push_str("];");
+    output.push('\n');
+
+    output
+}
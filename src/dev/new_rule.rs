@@ -0,0 +1,228 @@
+//! `cargo dev new-rule`: scaffold a new lint rule across the registry, its
+//! plugin module, and the test harness.
+//!
+//! This mirrors Clippy's `clippy_dev new_lint`/`update_lints`: given a code
+//! prefix and a rule name it performs the mechanical registration steps that
+//! are otherwise easy to get subtly wrong:
+//!
+//! 1. write a stub check function into the target plugin module, shaped like
+//!    the existing [`getattr_with_constant`]/[`unnecessary_builtin_import`]
+//!    plugins;
+//! 2. add the `CheckCode` variant and matching `CheckKind` arm;
+//! 3. regenerate `registry_gen.rs` via the existing [`gen`] codegen; and
+//! 4. create an empty fixture and a `#[test_case]` line in the plugin's
+//!    `tests` module.
+//!
+//! [`getattr_with_constant`]: crate::flake8_bugbear::plugins::getattr_with_constant
+//! [`unnecessary_builtin_import`]: crate::pyupgrade::plugins::unnecessary_builtin_import
+//! [`gen`]: crate::dev::generate_check_code_prefix
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+use crate::dev::generate_check_code_prefix;
+
+/// The arguments accepted by the `new-rule` subcommand.
+#[derive(Debug)]
+pub struct Args {
+    /// The rule's code, e.g. `B010`.
+    pub code: String,
+    /// The plugin the rule belongs to, e.g. `flake8_bugbear`.
+    pub plugin: String,
+    /// The `CamelCase` name of the rule, e.g. `SetAttrWithConstant`.
+    pub name: String,
+}
+
+/// Scaffold the rule described by `args`, writing every file in place.
+pub fn main(args: &Args) -> Result<()> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let snake = to_snake_case(&args.name);
+
+    write_stub(root, args, &snake)?;
+    register_check_code(root, args)?;
+    generate_check_code_prefix::main()?;
+    write_fixture_and_test(root, args)?;
+
+    Ok(())
+}
+
+/// Write the stub plugin module, refusing to clobber an existing file.
+fn write_stub(root: &Path, args: &Args, snake: &str) -> Result<()> {
+    let path = root
+        .join("src")
+        .join(&args.plugin)
+        .join("plugins")
+        .join(format!("{snake}.rs"));
+    if path.exists() {
+        bail!("{} already exists", path.display());
+    }
+    fs::write(&path, stub(args, snake))
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(())
+}
+
+/// Render the body of the stub plugin module.
+fn stub(args: &Args, snake: &str) -> String {
+    let Args { code, name, .. } = args;
+    format!(
+        "use rustpython_ast::Expr;
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::registry::{{Check, CheckKind}};
+
+/// {code}
+pub fn {snake}(checker: &mut Checker, expr: &Expr) {{
+    // TODO: implement the {name} check.
+    checker.add_check(Check::new(CheckKind::{name}, Range::from_located(expr)));
+}}
+"
+    )
+}
+
+/// Insert the `CheckCode` variant and its `CheckKind` arm in `registry.rs`.
+///
+/// The variants are kept sorted, so we splice the new code in ahead of the
+/// first existing code that sorts after it rather than appending.
+fn register_check_code(root: &Path, args: &Args) -> Result<()> {
+    let path = root.join("src").join("registry.rs");
+    let mut contents = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    if contents.contains(&format!("{} => CheckKind::{}", args.code, args.name)) {
+        bail!("{} is already registered", args.code);
+    }
+
+    // (1) Add the `CheckCode` variant, keeping the enum's variants sorted.
+    insert_sorted_variant(
+        &mut contents,
+        "pub enum CheckCode {",
+        &format!("{},", args.code),
+    )
+    .with_context(|| "Failed to locate the `CheckCode` enum")?;
+
+    // (2) Add the `CheckKind` variant, likewise sorted.
+    insert_sorted_variant(
+        &mut contents,
+        "pub enum CheckKind {",
+        &format!("{},", args.name),
+    )
+    .with_context(|| "Failed to locate the `CheckKind` enum")?;
+
+    // (3) Map the new code to its kind in the `kind()` dispatch.
+    insert_sorted_variant(
+        &mut contents,
+        "pub fn kind(&self) -> CheckKind {",
+        &format!("CheckCode::{} => CheckKind::{},", args.code, args.name),
+    )
+    .with_context(|| "Failed to locate the `kind()` dispatch")?;
+
+    fs::write(&path, contents)
+        .with_context(|| format!("Failed to update {}", path.display()))?;
+    Ok(())
+}
+
+/// Splice `entry` into the block opened by `anchor`, ahead of the first existing
+/// line that sorts after it, so the block stays lexically sorted. The inserted
+/// line copies the indentation of the line it precedes.
+fn insert_sorted_variant(contents: &mut String, anchor: &str, entry: &str) -> Result<()> {
+    let block_start = contents
+        .find(anchor)
+        .map(|i| i + anchor.len())
+        .context("anchor not found")?;
+    let block_len = contents[block_start..]
+        .find('}')
+        .context("unterminated block")?;
+    let block = &contents[block_start..block_start + block_len];
+
+    // Find the first non-empty line whose trimmed text sorts after `entry`,
+    // skipping comments and lines that open a nested block (`match self {`).
+    let mut insert_at = block_start + block_len;
+    let mut indent = String::new();
+    let mut offset = block_start;
+    for line in block.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if !trimmed.is_empty()
+            && !trimmed.starts_with("//")
+            && !trimmed.ends_with('{')
+            && trimmed > entry
+        {
+            insert_at = offset;
+            indent = line[..line.len() - line.trim_start().len()]
+                .trim_end_matches('\n')
+                .to_string();
+            break;
+        }
+        offset += line.len();
+    }
+    if indent.is_empty() {
+        // Fall back to a four-space indent when no existing line sorts after
+        // `entry`, i.e. when appending at the end of the block.
+        indent = "    ".to_string();
+    }
+    contents.insert_str(insert_at, &format!("{indent}{entry}\n"));
+    Ok(())
+}
+
+/// Create the empty fixture and append a `#[test_case]` line for the rule.
+fn write_fixture_and_test(root: &Path, args: &Args) -> Result<()> {
+    let fixture = fixture_path(root, args);
+    if !fixture.exists() {
+        fs::create_dir_all(fixture.parent().unwrap())?;
+        fs::write(&fixture, "")
+            .with_context(|| format!("Failed to write {}", fixture.display()))?;
+    }
+
+    // Append a `#[test_case]` line to the plugin module's `tests` block, mirroring
+    // the existing per-fixture cases (e.g. `#[test_case(CheckCode::B009, Path::new("B009.py"); ...)]`).
+    let module = root
+        .join("src")
+        .join(&args.plugin)
+        .join("mod.rs");
+    let contents = fs::read_to_string(&module)
+        .with_context(|| format!("Failed to read {}", module.display()))?;
+    let case = format!(
+        "    #[test_case(CheckCode::{code}, Path::new(\"{code}.py\"); \"{code}\")]\n",
+        code = args.code
+    );
+    if contents.contains(&case) {
+        return Ok(());
+    }
+    // Insert ahead of the `fn checks(` test body that the cases decorate.
+    let anchor = contents
+        .find("fn checks(")
+        .or_else(|| contents.find("fn check_path("))
+        .with_context(|| format!("No test harness found in {}", module.display()))?;
+    let line_start = contents[..anchor].rfind('\n').map_or(0, |i| i + 1);
+    let mut updated = contents.clone();
+    updated.insert_str(line_start, &case);
+    fs::write(&module, updated)
+        .with_context(|| format!("Failed to update {}", module.display()))?;
+    Ok(())
+}
+
+/// The fixture path for a rule, e.g. `resources/test/fixtures/<plugin>/<code>.py`.
+fn fixture_path(root: &Path, args: &Args) -> PathBuf {
+    root.join("resources")
+        .join("test")
+        .join("fixtures")
+        .join(&args.plugin)
+        .join(format!("{}.py", args.code))
+}
+
+/// Convert a `CamelCase` rule name to the `snake_case` module name.
+fn to_snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
@@ -1,15 +1,13 @@
-use num_traits::ToPrimitive;
 use once_cell::sync::Lazy;
 use rustc_hash::{FxHashMap, FxHashSet};
-use rustpython_ast::{Constant, Expr, ExprKind, Keyword, Operator};
+use rustpython_ast::{Expr, Keyword};
 
+use crate::ast::constant_folding;
 use crate::ast::helpers::{compose_call_path, match_module_member, SimpleCallArgs};
 use crate::ast::types::Range;
+use crate::flake8_bandit::settings::Settings;
 use crate::registry::{Check, CheckKind};
 
-const WRITE_WORLD: u16 = 0o2;
-const EXECUTE_GROUP: u16 = 0o10;
-
 static PYSTAT_MAPPING: Lazy<FxHashMap<&'static str, u16>> = Lazy::new(|| {
     FxHashMap::from_iter([
         ("stat.ST_MODE", 0o0),
@@ -52,35 +50,13 @@ static PYSTAT_MAPPING: Lazy<FxHashMap<&'static str, u16>> = Lazy::new(|| {
     ])
 });
 
+/// Resolve a `stat.*` attribute leaf to its mode bits.
+fn resolve_stat(expr: &Expr) -> Option<u16> {
+    compose_call_path(expr).and_then(|path| PYSTAT_MAPPING.get(path.as_str()).copied())
+}
+
 fn get_int_value(expr: &Expr) -> Option<u16> {
-    match &expr.node {
-        ExprKind::Constant {
-            value: Constant::Int(value),
-            ..
-        } => value.to_u16(),
-        ExprKind::Attribute { .. } => {
-            if let Some(path) = compose_call_path(expr) {
-                PYSTAT_MAPPING.get(path.as_str()).copied()
-            } else {
-                None
-            }
-        }
-        ExprKind::BinOp { left, op, right } => {
-            if let (Some(left_value), Some(right_value)) =
-                (get_int_value(left), get_int_value(right))
-            {
-                match op {
-                    Operator::BitAnd => Some(left_value & right_value),
-                    Operator::BitOr => Some(left_value | right_value),
-                    Operator::BitXor => Some(left_value ^ right_value),
-                    _ => None,
-                }
-            } else {
-                None
-            }
-        }
-        _ => None,
-    }
+    constant_folding::eval_u16(expr, &resolve_stat)
 }
 
 /// S103
@@ -90,12 +66,13 @@ pub fn bad_file_permissions(
     keywords: &Vec<Keyword>,
     from_imports: &FxHashMap<&str, FxHashSet<&str>>,
     import_aliases: &FxHashMap<&str, &str>,
+    settings: &Settings,
 ) -> Option<Check> {
     if match_module_member(func, "os", "chmod", from_imports, import_aliases) {
         let call_args = SimpleCallArgs::new(args, keywords);
         if let Some(mode_arg) = call_args.get_argument("mode", Some(1)) {
             if let Some(int_value) = get_int_value(mode_arg) {
-                if (int_value & WRITE_WORLD > 0) || (int_value & EXECUTE_GROUP > 0) {
+                if int_value & settings.bad_file_permissions_mask > 0 {
                     return Some(Check::new(
                         CheckKind::BadFilePermissions(int_value),
                         Range::from_located(mode_arg),
@@ -0,0 +1,63 @@
+//! Settings for the `flake8-bandit` plugin.
+
+use ruff_macros::ConfigurationOptions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The world-writable and group-executable bits that, by default, make a file
+/// mode passed to `os.chmod` suspicious (`S103`).
+const DEFAULT_BAD_MODE_MASK: u16 = 0o12;
+
+#[derive(
+    Debug, PartialEq, Eq, Serialize, Deserialize, Default, ConfigurationOptions, JsonSchema,
+)]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "kebab-case",
+    rename = "Flake8BanditOptions"
+)]
+pub struct Options {
+    #[option(
+        default = "10",
+        value_type = "u16",
+        example = r#"
+            # Flag `os.chmod` modes that grant world-write or group-execute.
+            bad-file-permissions-mask = 0o12
+        "#
+    )]
+    /// The permission bits whose presence in an `os.chmod` mode triggers
+    /// `S103`. Defaults to world-writable (`0o2`) and group-executable
+    /// (`0o10`).
+    pub bad_file_permissions_mask: Option<u16>,
+}
+
+#[derive(Debug, Hash)]
+pub struct Settings {
+    pub bad_file_permissions_mask: u16,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            bad_file_permissions_mask: DEFAULT_BAD_MODE_MASK,
+        }
+    }
+}
+
+impl From<Options> for Settings {
+    fn from(options: Options) -> Self {
+        Self {
+            bad_file_permissions_mask: options
+                .bad_file_permissions_mask
+                .unwrap_or(DEFAULT_BAD_MODE_MASK),
+        }
+    }
+}
+
+impl From<Settings> for Options {
+    fn from(settings: Settings) -> Self {
+        Self {
+            bad_file_permissions_mask: Some(settings.bad_file_permissions_mask),
+        }
+    }
+}
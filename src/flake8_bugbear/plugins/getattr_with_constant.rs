@@ -46,7 +46,8 @@ pub fn getattr_with_constant(checker: &mut Checker, expr: &Expr, func: &Expr, ar
     }
 
     let mut check = Check::new(CheckKind::GetAttrWithConstant, Range::from_located(expr));
-    if checker.patch(check.kind.code()) {
+    if checker.patch(check.kind.code()) && checker.settings.flake8_bugbear.getattr_with_constant_fix
+    {
         let mut generator = SourceCodeGenerator::new(
             checker.style.indentation(),
             checker.style.quote(),
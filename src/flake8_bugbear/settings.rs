@@ -0,0 +1,57 @@
+//! Settings for the `flake8-bugbear` plugin.
+
+use ruff_macros::ConfigurationOptions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, PartialEq, Eq, Serialize, Deserialize, Default, ConfigurationOptions, JsonSchema,
+)]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "kebab-case",
+    rename = "Flake8BugbearOptions"
+)]
+pub struct Options {
+    #[option(
+        default = r#"true"#,
+        value_type = "bool",
+        example = r#"
+            # Report `getattr(obj, "attr")` without offering an autofix.
+            getattr-with-constant-fix = false
+        "#
+    )]
+    /// Whether `B009` rewrites `getattr(obj, "constant")` to `obj.constant`
+    /// automatically. When disabled, the diagnostic is still reported but no
+    /// fix is attached.
+    pub getattr_with_constant_fix: Option<bool>,
+}
+
+#[derive(Debug, Hash)]
+pub struct Settings {
+    pub getattr_with_constant_fix: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            getattr_with_constant_fix: true,
+        }
+    }
+}
+
+impl From<Options> for Settings {
+    fn from(options: Options) -> Self {
+        Self {
+            getattr_with_constant_fix: options.getattr_with_constant_fix.unwrap_or(true),
+        }
+    }
+}
+
+impl From<Settings> for Options {
+    fn from(settings: Settings) -> Self {
+        Self {
+            getattr_with_constant_fix: Some(settings.getattr_with_constant_fix),
+        }
+    }
+}
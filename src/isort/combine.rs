@@ -0,0 +1,54 @@
+//! The `combine-imports` normalization pass: merge separate `from X import a`
+//! and `from X import b` statements from the same module into one.
+//!
+//! This reuses the shared [`import_edits`](crate::autofix::import_edits) merge
+//! subsystem. Only top-level statements are considered, so imports nested under
+//! `if TYPE_CHECKING:`, `try`/`except`, or a function body are never merged
+//! across scopes, and any group containing a `*` import is skipped.
+
+use rustpython_ast::{Stmt, StmtKind};
+
+use crate::autofix::import_edits::{
+    duplicate_from_imports, import_key, merge_from_imports, ImportKey,
+};
+use crate::isort::settings::Settings;
+use crate::source_code_style::SourceCodeStyleDetector;
+
+/// A merge the pass wants to apply: the statements to replace and the single
+/// combined statement that replaces them.
+pub struct Combination<'a> {
+    pub key: ImportKey,
+    pub statements: Vec<&'a Stmt>,
+    pub combined: String,
+}
+
+/// Compute the combinations for a run of sibling top-level `body` statements.
+/// Returns an empty `Vec` when `combine_imports` is disabled.
+pub fn combine_imports<'a>(
+    body: &'a [&'a Stmt],
+    settings: &Settings,
+    style: &SourceCodeStyleDetector,
+) -> Vec<Combination<'a>> {
+    if !settings.combine_imports {
+        return Vec::new();
+    }
+    duplicate_from_imports(body)
+        .into_iter()
+        // `duplicate_from_imports` leaves `*` imports out of its groups, so skip
+        // any key that also has a `*` import in `body`.
+        .filter(|(key, _)| {
+            !body.iter().any(|stmt| {
+                import_key(stmt).as_ref() == Some(key)
+                    && matches!(&stmt.node, StmtKind::ImportFrom { names, .. }
+                        if names.iter().any(|alias| alias.node.name == "*"))
+            })
+        })
+        .filter_map(|(key, statements)| {
+            merge_from_imports(&key, &statements, style).map(|combined| Combination {
+                key,
+                statements,
+                combined,
+            })
+        })
+        .collect()
+}
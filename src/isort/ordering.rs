@@ -0,0 +1,52 @@
+//! The import comparator, honoring the isort ordering controls.
+//!
+//! `force_sort_within_sections` sorts straight `import` and `from` statements
+//! together purely by name; otherwise straights sort ahead of froms.
+//! `case_sensitive` selects ASCII ordering over the default case-folded
+//! ordering.
+
+use std::cmp::Ordering;
+
+use crate::isort::settings::Settings;
+
+/// Whether an import entry is a plain `import x` (straight) or a
+/// `from x import y` (from).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportKind {
+    Straight,
+    From,
+}
+
+/// An orderable import entry: its kind and the name used for comparison.
+#[derive(Debug, Clone)]
+pub struct ImportEntry {
+    pub kind: ImportKind,
+    pub name: String,
+}
+
+/// Compare two entries per `settings`.
+pub fn cmp(a: &ImportEntry, b: &ImportEntry, settings: &Settings) -> Ordering {
+    if !settings.force_sort_within_sections {
+        // Straight imports sort ahead of from imports.
+        let rank = |kind: ImportKind| match kind {
+            ImportKind::Straight => 0,
+            ImportKind::From => 1,
+        };
+        match rank(a.kind).cmp(&rank(b.kind)) {
+            Ordering::Equal => {}
+            ordering => return ordering,
+        }
+    }
+    cmp_name(&a.name, &b.name, settings)
+}
+
+/// Compare two names, case-sensitively or case-folded per `settings`.
+fn cmp_name(a: &str, b: &str, settings: &Settings) -> Ordering {
+    if settings.case_sensitive {
+        a.cmp(b)
+    } else {
+        a.to_ascii_lowercase()
+            .cmp(&b.to_ascii_lowercase())
+            .then_with(|| a.cmp(b))
+    }
+}
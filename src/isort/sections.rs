@@ -0,0 +1,38 @@
+//! Assigning imports to sections and emitting those sections in order.
+//!
+//! User-defined sections (`known_sections`) are consulted before the built-in
+//! first/third-party detection, so a module prefix listed in a custom section
+//! overrides the default classification. Sections are then emitted in the
+//! configured `section_order`.
+
+use crate::isort::settings::Settings;
+
+/// Resolve the section a module belongs to, preferring user-defined sections.
+/// Returns the custom section name when a prefix matches; otherwise `None`, so
+/// the caller falls back to the built-in detection.
+pub fn custom_section(module: &str, settings: &Settings) -> Option<String> {
+    settings
+        .known_sections
+        .iter()
+        .filter(|(_, prefixes)| prefixes.iter().any(|prefix| matches_prefix(module, prefix)))
+        // Prefer the most specific (longest) matching prefix across sections.
+        .max_by_key(|(_, prefixes)| {
+            prefixes
+                .iter()
+                .filter(|prefix| matches_prefix(module, prefix))
+                .map(String::len)
+                .max()
+                .unwrap_or(0)
+        })
+        .map(|(section, _)| section.clone())
+}
+
+/// Does `module` fall under `prefix` (exact, or a dotted sub-module)?
+fn matches_prefix(module: &str, prefix: &str) -> bool {
+    module == prefix || module.strip_prefix(prefix).is_some_and(|rest| rest.starts_with('.'))
+}
+
+/// The section names to emit, in their configured order.
+pub fn ordered_sections(settings: &Settings) -> Vec<String> {
+    settings.section_order.clone()
+}
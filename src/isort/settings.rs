@@ -1,6 +1,6 @@
 //! Settings for the `isort` plugin.
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 use ruff_macros::ConfigurationOptions;
 use schemars::JsonSchema;
@@ -66,6 +66,18 @@ pub struct Options {
     /// Combines as imports on the same line. See isort's [`combine-as-imports`](https://pycqa.github.io/isort/docs/configuration/options.html#combine-as-imports)
     /// option.
     pub combine_as_imports: Option<bool>,
+    #[option(
+        default = r#"false"#,
+        value_type = "bool",
+        example = r#"
+            combine-imports = true
+        "#
+    )]
+    /// Merge separate `from x import a` and `from x import b` statements from
+    /// the same module into a single `from x import a, b`. See isort's
+    /// [`combine-imports`](https://pycqa.github.io/isort/docs/configuration/options.html#combine-imports)
+    /// option.
+    pub combine_imports: Option<bool>,
     #[option(
         default = r#"true"#,
         value_type = "bool",
@@ -88,6 +100,37 @@ pub struct Options {
     /// Order imports by type, which is determined by case, in addition to
     /// alphabetically.
     pub order_by_type: Option<bool>,
+    #[option(
+        default = r#"false"#,
+        value_type = "bool",
+        example = r#"
+            force-sort-within-sections = true
+        "#
+    )]
+    /// Sort plain `import` and `from` statements together, purely
+    /// alphabetically, instead of grouping straight imports before from
+    /// imports within a section.
+    pub force_sort_within_sections: Option<bool>,
+    #[option(
+        default = r#"false"#,
+        value_type = "bool",
+        example = r#"
+            case-sensitive = true
+        "#
+    )]
+    /// Compare module and member names case-sensitively (ASCII order) rather
+    /// than with the default case-folded ordering.
+    pub case_sensitive: Option<bool>,
+    #[option(
+        default = r#"0"#,
+        value_type = "usize",
+        example = r#"
+            lines-between-types = 1
+        "#
+    )]
+    /// The number of blank lines to insert between the straight-import block
+    /// and the from-import block within a single section.
+    pub lines_between_types: Option<usize>,
     #[option(
         default = r#"[]"#,
         value_type = "Vec<String>",
@@ -118,12 +161,36 @@ pub struct Options {
     /// A list of modules to consider standard-library, in addition to those
     /// known to Ruff in advance.
     pub extra_standard_library: Option<Vec<String>>,
+    #[option(
+        default = r#"["future", "standard-library", "third-party", "first-party", "local-folder"]"#,
+        value_type = "Vec<String>",
+        example = r#"
+            section-order = ["future", "standard-library", "third-party", "first-party", "local-folder"]
+        "#
+    )]
+    /// The order in which import sections are emitted, by name. Custom sections
+    /// defined via `known-sections` may be interleaved here to place them
+    /// between the built-in sections.
+    pub section_order: Option<Vec<String>>,
+    #[option(
+        default = r#"{}"#,
+        value_type = "BTreeMap<String, Vec<String>>",
+        example = r#"
+            # Put Django imports in their own section.
+            known-sections = { "django" = ["django", "rest_framework"] }
+        "#
+    )]
+    /// User-defined import sections, mapping a section name to the module
+    /// prefixes assigned to it. Consulted before the built-in first/third-party
+    /// detection, so a prefix listed here overrides the default classification.
+    pub known_sections: Option<BTreeMap<String, Vec<String>>>,
 }
 
 #[derive(Debug, Hash)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct Settings {
     pub combine_as_imports: bool,
+    pub combine_imports: bool,
     pub force_wrap_aliases: bool,
     pub split_on_trailing_comma: bool,
     pub force_single_line: bool,
@@ -131,21 +198,46 @@ pub struct Settings {
     pub known_first_party: BTreeSet<String>,
     pub known_third_party: BTreeSet<String>,
     pub order_by_type: bool,
+    pub force_sort_within_sections: bool,
+    pub case_sensitive: bool,
+    pub lines_between_types: usize,
     pub extra_standard_library: BTreeSet<String>,
+    pub section_order: Vec<String>,
+    pub known_sections: BTreeMap<String, Vec<String>>,
+}
+
+/// The built-in import sections, in their conventional order.
+fn default_section_order() -> Vec<String> {
+    [
+        "future",
+        "standard-library",
+        "third-party",
+        "first-party",
+        "local-folder",
+    ]
+    .iter()
+    .map(ToString::to_string)
+    .collect()
 }
 
 impl Default for Settings {
     fn default() -> Self {
         Self {
             combine_as_imports: false,
+            combine_imports: false,
             force_wrap_aliases: false,
             split_on_trailing_comma: true,
             force_single_line: false,
             order_by_type: true,
+            force_sort_within_sections: false,
+            case_sensitive: false,
+            lines_between_types: 0,
             single_line_exclusions: BTreeSet::new(),
             known_first_party: BTreeSet::new(),
             known_third_party: BTreeSet::new(),
             extra_standard_library: BTreeSet::new(),
+            section_order: default_section_order(),
+            known_sections: BTreeMap::new(),
         }
     }
 }
@@ -154,10 +246,14 @@ impl From<Options> for Settings {
     fn from(options: Options) -> Self {
         Self {
             combine_as_imports: options.combine_as_imports.unwrap_or(false),
+            combine_imports: options.combine_imports.unwrap_or(false),
             force_wrap_aliases: options.force_wrap_aliases.unwrap_or(false),
             split_on_trailing_comma: options.split_on_trailing_comma.unwrap_or(true),
             force_single_line: options.force_single_line.unwrap_or(false),
             order_by_type: options.order_by_type.unwrap_or(true),
+            force_sort_within_sections: options.force_sort_within_sections.unwrap_or(false),
+            case_sensitive: options.case_sensitive.unwrap_or(false),
+            lines_between_types: options.lines_between_types.unwrap_or(0),
             single_line_exclusions: BTreeSet::from_iter(
                 options.single_line_exclusions.unwrap_or_default(),
             ),
@@ -166,6 +262,8 @@ impl From<Options> for Settings {
             extra_standard_library: BTreeSet::from_iter(
                 options.extra_standard_library.unwrap_or_default(),
             ),
+            section_order: options.section_order.unwrap_or_else(default_section_order),
+            known_sections: options.known_sections.unwrap_or_default(),
         }
     }
 }
@@ -174,14 +272,20 @@ impl From<Settings> for Options {
     fn from(settings: Settings) -> Self {
         Self {
             combine_as_imports: Some(settings.combine_as_imports),
+            combine_imports: Some(settings.combine_imports),
             force_wrap_aliases: Some(settings.force_wrap_aliases),
             split_on_trailing_comma: Some(settings.split_on_trailing_comma),
             force_single_line: Some(settings.force_single_line),
             order_by_type: Some(settings.order_by_type),
+            force_sort_within_sections: Some(settings.force_sort_within_sections),
+            case_sensitive: Some(settings.case_sensitive),
+            lines_between_types: Some(settings.lines_between_types),
             single_line_exclusions: Some(settings.single_line_exclusions.into_iter().collect()),
             known_first_party: Some(settings.known_first_party.into_iter().collect()),
             known_third_party: Some(settings.known_third_party.into_iter().collect()),
             extra_standard_library: Some(settings.extra_standard_library.into_iter().collect()),
+            section_order: Some(settings.section_order),
+            known_sections: Some(settings.known_sections),
         }
     }
 }
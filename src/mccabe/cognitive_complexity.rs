@@ -0,0 +1,201 @@
+//! Compute the Cognitive Complexity of a function.
+//!
+//! Cognitive Complexity, as popularized by SonarSource, differs from McCabe's
+//! cyclomatic complexity in two ways: it does not penalize flat, `switch`-like
+//! code, and it charges a nesting premium for control flow buried inside other
+//! control flow. Each structure that breaks the linear flow scores `1 +
+//! nesting`, while structures that merely continue an existing one (`elif`,
+//! `else`, `break`, `continue`) score a flat `1`.
+
+use rustpython_ast::{Boolop, Expr, ExprKind, Stmt, StmtKind};
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::registry::{Check, CheckKind};
+
+#[derive(Default)]
+struct Counter {
+    score: usize,
+}
+
+impl Counter {
+    /// Add the nesting-weighted cost of a structure that breaks the flow.
+    fn structure(&mut self, nesting: usize) {
+        self.score += 1 + nesting;
+    }
+
+    /// Add the flat cost of a structure that continues the flow.
+    fn flat(&mut self) {
+        self.score += 1;
+    }
+
+    fn body(&mut self, stmts: &[Stmt], nesting: usize) {
+        for stmt in stmts {
+            self.stmt(stmt, nesting);
+        }
+    }
+
+    fn stmt(&mut self, stmt: &Stmt, nesting: usize) {
+        match &stmt.node {
+            StmtKind::If { test, body, orelse } => {
+                self.structure(nesting);
+                self.boolop(test, None);
+                self.body(body, nesting + 1);
+                self.orelse(orelse, nesting);
+            }
+            StmtKind::While { test, body, orelse } => {
+                self.structure(nesting);
+                self.boolop(test, None);
+                self.body(body, nesting + 1);
+                if !orelse.is_empty() {
+                    self.flat();
+                    self.body(orelse, nesting + 1);
+                }
+            }
+            StmtKind::For { body, orelse, .. } | StmtKind::AsyncFor { body, orelse, .. } => {
+                self.structure(nesting);
+                self.body(body, nesting + 1);
+                if !orelse.is_empty() {
+                    self.flat();
+                    self.body(orelse, nesting + 1);
+                }
+            }
+            StmtKind::Try {
+                body,
+                handlers,
+                orelse,
+                finalbody,
+            } => {
+                self.body(body, nesting);
+                for handler in handlers {
+                    let rustpython_ast::ExcepthandlerKind::ExceptHandler { body, .. } =
+                        &handler.node;
+                    self.structure(nesting);
+                    self.body(body, nesting + 1);
+                }
+                if !orelse.is_empty() {
+                    self.flat();
+                    self.body(orelse, nesting + 1);
+                }
+                self.body(finalbody, nesting);
+            }
+            StmtKind::With { body, .. } | StmtKind::AsyncWith { body, .. } => {
+                self.body(body, nesting);
+            }
+            // A nested definition adds `+1` and raises the nesting for its body.
+            StmtKind::FunctionDef { body, .. } | StmtKind::AsyncFunctionDef { body, .. } => {
+                self.flat();
+                self.body(body, nesting + 1);
+            }
+            StmtKind::ClassDef { body, .. } => self.body(body, nesting),
+            StmtKind::Return { value: Some(value) }
+            | StmtKind::Expr { value }
+            | StmtKind::AugAssign { value, .. } => self.expr(value, nesting),
+            StmtKind::Assign { value, .. } => self.expr(value, nesting),
+            StmtKind::Break | StmtKind::Continue => self.flat(),
+            _ => {}
+        }
+    }
+
+    /// Handle the `orelse` of an `if`: a lone nested `if` is an `elif`, anything
+    /// else is an `else`. Both score a flat `1`.
+    fn orelse(&mut self, orelse: &[Stmt], nesting: usize) {
+        match orelse {
+            [] => {}
+            [stmt] if matches!(stmt.node, StmtKind::If { .. }) => {
+                if let StmtKind::If { test, body, orelse } = &stmt.node {
+                    self.flat();
+                    self.boolop(test, None);
+                    self.body(body, nesting + 1);
+                    self.orelse(orelse, nesting);
+                }
+            }
+            _ => {
+                self.flat();
+                self.body(orelse, nesting + 1);
+            }
+        }
+    }
+
+    fn expr(&mut self, expr: &Expr, nesting: usize) {
+        match &expr.node {
+            ExprKind::BoolOp { .. } => self.boolop(expr, None),
+            ExprKind::IfExp { test, body, orelse } => {
+                self.structure(nesting);
+                self.boolop(test, None);
+                self.expr(body, nesting + 1);
+                self.expr(orelse, nesting + 1);
+            }
+            ExprKind::ListComp { elt, generators }
+            | ExprKind::SetComp { elt, generators }
+            | ExprKind::GeneratorExp { elt, generators } => {
+                self.structure(nesting);
+                self.expr(elt, nesting + 1);
+                for generator in generators {
+                    for cond in &generator.ifs {
+                        self.expr(cond, nesting + 1);
+                    }
+                }
+            }
+            ExprKind::DictComp {
+                key,
+                value,
+                generators,
+            } => {
+                self.structure(nesting);
+                self.expr(key, nesting + 1);
+                self.expr(value, nesting + 1);
+                for generator in generators {
+                    for cond in &generator.ifs {
+                        self.expr(cond, nesting + 1);
+                    }
+                }
+            }
+            ExprKind::Lambda { body, .. } => {
+                self.flat();
+                self.expr(body, nesting + 1);
+            }
+            ExprKind::BinOp { left, right, .. } => {
+                self.expr(left, nesting);
+                self.expr(right, nesting);
+            }
+            ExprKind::UnaryOp { operand, .. } => self.expr(operand, nesting),
+            ExprKind::Call { args, .. } => {
+                for arg in args {
+                    self.expr(arg, nesting);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Score one point for each run of like operators in a boolean-operator
+    /// chain, so every switch between `and` and `or` starts a new point:
+    /// `a and b and c` scores 1, `a and b or c` scores 2. Operands are not
+    /// counted.
+    fn boolop(&mut self, expr: &Expr, parent: Option<&Boolop>) {
+        if let ExprKind::BoolOp { op, values } = &expr.node {
+            if parent != Some(op) {
+                self.flat();
+            }
+            for value in values {
+                self.boolop(value, Some(op));
+            }
+        }
+    }
+}
+
+/// C901
+pub fn cognitive_complexity(checker: &mut Checker, stmt: &Stmt, name: &str, body: &[Stmt]) -> usize {
+    let mut counter = Counter::default();
+    counter.body(body, 0);
+
+    let max_cognitive_complexity = checker.settings.mccabe.max_cognitive_complexity;
+    if counter.score > max_cognitive_complexity {
+        checker.add_check(Check::new(
+            CheckKind::CognitiveComplexity(name.to_string(), counter.score, max_cognitive_complexity),
+            Range::from_located(stmt),
+        ));
+    }
+    counter.score
+}
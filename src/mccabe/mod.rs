@@ -1,5 +1,7 @@
 pub mod checks;
+pub mod cognitive_complexity;
 pub mod settings;
+pub mod unconditional_recursion;
 
 #[cfg(test)]
 mod tests {
@@ -20,11 +22,43 @@ mod tests {
         let checks = test_path(
             Path::new("./resources/test/fixtures/mccabe/C901.py"),
             &Settings {
-                mccabe: mccabe::settings::Settings { max_complexity },
+                mccabe: mccabe::settings::Settings {
+                    max_complexity,
+                    ..mccabe::settings::Settings::default()
+                },
                 ..Settings::for_rules(vec![CheckCode::C901])
             },
         )?;
         insta::assert_yaml_snapshot!(snapshot, checks);
         Ok(())
     }
+
+    #[test_case(0)]
+    #[test_case(3)]
+    #[test_case(10)]
+    fn max_cognitive_complexity(max_cognitive_complexity: usize) -> Result<()> {
+        let snapshot = format!("max_cognitive_complexity_{max_cognitive_complexity}");
+        let checks = test_path(
+            Path::new("./resources/test/fixtures/mccabe/C901.py"),
+            &Settings {
+                mccabe: mccabe::settings::Settings {
+                    max_cognitive_complexity,
+                    ..mccabe::settings::Settings::default()
+                },
+                ..Settings::for_rules(vec![CheckCode::C901])
+            },
+        )?;
+        insta::assert_yaml_snapshot!(snapshot, checks);
+        Ok(())
+    }
+
+    #[test]
+    fn unconditional_recursion() -> Result<()> {
+        let checks = test_path(
+            Path::new("./resources/test/fixtures/mccabe/C902.py"),
+            &Settings::for_rule(CheckCode::C902),
+        )?;
+        insta::assert_yaml_snapshot!(checks);
+        Ok(())
+    }
 }
@@ -0,0 +1,71 @@
+//! Settings for the `mccabe` plugin.
+
+use ruff_macros::ConfigurationOptions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, PartialEq, Eq, Serialize, Deserialize, Default, ConfigurationOptions, JsonSchema,
+)]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "kebab-case",
+    rename = "McCabeOptions"
+)]
+pub struct Options {
+    #[option(
+        default = "10",
+        value_type = "usize",
+        example = r#"
+            # Flag errors (`C901`) whenever the complexity level exceeds 5.
+            max-complexity = 5
+        "#
+    )]
+    /// The maximum McCabe complexity to allow before triggering `C901` errors.
+    pub max_complexity: Option<usize>,
+    #[option(
+        default = "15",
+        value_type = "usize",
+        example = r#"
+            # Flag errors (`C901`) whenever the cognitive complexity exceeds 10.
+            max-cognitive-complexity = 10
+        "#
+    )]
+    /// The maximum cognitive complexity to allow before triggering `C901`
+    /// errors. Unlike cyclomatic complexity, cognitive complexity penalizes
+    /// deeply nested control flow over flat, `switch`-like code.
+    pub max_cognitive_complexity: Option<usize>,
+}
+
+#[derive(Debug, Hash)]
+pub struct Settings {
+    pub max_complexity: usize,
+    pub max_cognitive_complexity: usize,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            max_complexity: 10,
+            max_cognitive_complexity: 15,
+        }
+    }
+}
+
+impl From<Options> for Settings {
+    fn from(options: Options) -> Self {
+        Self {
+            max_complexity: options.max_complexity.unwrap_or(10),
+            max_cognitive_complexity: options.max_cognitive_complexity.unwrap_or(15),
+        }
+    }
+}
+
+impl From<Settings> for Options {
+    fn from(settings: Settings) -> Self {
+        Self {
+            max_complexity: Some(settings.max_complexity),
+            max_cognitive_complexity: Some(settings.max_cognitive_complexity),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! Detect functions that always call themselves before they can return.
+
+use rustpython_ast::{Expr, ExprKind, Stmt, StmtKind};
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::registry::{Check, CheckKind};
+
+/// The result of propagating reachability through a block of statements.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Flow {
+    /// Every path through the block reaches a self-call before leaving it.
+    Recurses,
+    /// Some path leaves the block (via `return`/`raise`) without recursing.
+    Exits,
+    /// Control may fall off the end of the block without recursing, continuing
+    /// into the following statements.
+    Fallthrough,
+}
+
+impl Flow {
+    /// Join the two arms of a branch into a single flow. A branch only forces
+    /// recursion when *both* arms recurse; a reachable exit wins over a
+    /// fall-through, which in turn wins over recursion.
+    fn join(self, other: Flow) -> Flow {
+        match (self, other) {
+            (Flow::Exits, _) | (_, Flow::Exits) => Flow::Exits,
+            (Flow::Fallthrough, _) | (_, Flow::Fallthrough) => Flow::Fallthrough,
+            (Flow::Recurses, Flow::Recurses) => Flow::Recurses,
+        }
+    }
+}
+
+/// Resolve the name called by `func` to the enclosing function, accounting for
+/// `self.name()`/`cls.name()` method calls.
+fn is_self_call(func: &Expr, name: &str) -> bool {
+    match &func.node {
+        ExprKind::Name { id, .. } => id == name,
+        ExprKind::Attribute { value, attr, .. } => {
+            attr == name
+                && matches!(&value.node, ExprKind::Name { id, .. } if id == "self" || id == "cls")
+        }
+        _ => false,
+    }
+}
+
+/// Return the location of a direct call to `name` in a part of `expr` that is
+/// always evaluated: the first operand of a boolean chain, both sides of a
+/// binary operation, a unary operand, the test of a conditional expression (or
+/// its arms, when both call `name`), a call's target and positional arguments,
+/// and awaited or starred values.
+fn self_call_site(expr: &Expr, name: &str) -> Option<Range> {
+    if let ExprKind::Call { func, .. } = &expr.node {
+        if is_self_call(func, name) {
+            return Some(Range::from_located(expr));
+        }
+    }
+    match &expr.node {
+        ExprKind::BoolOp { values, .. } => values.first().and_then(|v| self_call_site(v, name)),
+        ExprKind::BinOp { left, right, .. } => {
+            self_call_site(left, name).or_else(|| self_call_site(right, name))
+        }
+        ExprKind::UnaryOp { operand, .. } => self_call_site(operand, name),
+        ExprKind::IfExp { test, body, orelse } => self_call_site(test, name).or_else(|| {
+            self_call_site(body, name).filter(|_| self_call_site(orelse, name).is_some())
+        }),
+        ExprKind::Call { func, args, .. } => self_call_site(func, name)
+            .or_else(|| args.iter().find_map(|arg| self_call_site(arg, name))),
+        ExprKind::Await { value } | ExprKind::Starred { value, .. } => self_call_site(value, name),
+        _ => None,
+    }
+}
+
+/// Propagate reachability through a single statement.
+fn statement_flow(stmt: &Stmt, name: &str, site: &mut Option<Range>) -> Flow {
+    match &stmt.node {
+        StmtKind::Expr { value } => match self_call_site(value, name) {
+            Some(range) => {
+                site.get_or_insert(range);
+                Flow::Recurses
+            }
+            None => Flow::Fallthrough,
+        },
+        StmtKind::Return { value: Some(value) } => match self_call_site(value, name) {
+            Some(range) => {
+                site.get_or_insert(range);
+                Flow::Recurses
+            }
+            None => Flow::Exits,
+        },
+        StmtKind::Return { value: None } | StmtKind::Raise { .. } => Flow::Exits,
+        StmtKind::If { body, orelse, .. } => {
+            let body = block_flow(body, name, site);
+            let orelse = if orelse.is_empty() {
+                Flow::Fallthrough
+            } else {
+                block_flow(orelse, name, site)
+            };
+            body.join(orelse)
+        }
+        // `with` bodies are always entered, so they propagate directly.
+        StmtKind::With { body, .. } | StmtKind::AsyncWith { body, .. } => {
+            block_flow(body, name, site)
+        }
+        // Loop bodies are not provably entered, and `try`/`match` fall back to
+        // the conservative "control may continue" case.
+        _ => Flow::Fallthrough,
+    }
+}
+
+/// Propagate reachability through a sequence of statements, stopping at the
+/// first statement that either recurses or escapes. Statements after an
+/// unconditional `raise`/`return` are unreachable and ignored.
+fn block_flow(stmts: &[Stmt], name: &str, site: &mut Option<Range>) -> Flow {
+    for stmt in stmts {
+        match statement_flow(stmt, name, site) {
+            Flow::Fallthrough => continue,
+            flow => return flow,
+        }
+    }
+    Flow::Fallthrough
+}
+
+/// C902
+pub fn unconditional_recursion(checker: &mut Checker, stmt: &Stmt, name: &str, body: &[Stmt]) {
+    let mut site = None;
+    if matches!(block_flow(body, name, &mut site), Flow::Recurses) {
+        let range = site.unwrap_or_else(|| Range::from_located(stmt));
+        checker.add_check(Check::new(
+            CheckKind::UnconditionalRecursion(name.to_string()),
+            range,
+        ));
+    }
+}
@@ -0,0 +1,100 @@
+//! Identifier-naming checks built on the [`helpers`](super::helpers) classifiers.
+
+use rustpython_ast::{Arg, Expr, Location, Stmt};
+
+use crate::ast::types::Range;
+use crate::autofix::Fix;
+use crate::checkers::ast::Checker;
+use crate::pep8_naming::helpers;
+use crate::registry::{Check, CheckKind};
+
+/// N801: class name should use CapWords convention.
+pub fn invalid_class_name(checker: &mut Checker, stmt: &Stmt, name: &str) {
+    if helpers::is_cap_words(name) {
+        return;
+    }
+    let mut check = Check::new(
+        CheckKind::InvalidClassName(name.to_string()),
+        Range::from_located(stmt),
+    );
+    if checker.patch(check.kind.code()) {
+        let canonical = helpers::to_cap_words(name);
+        if canonical != name && helpers::is_cap_words(&canonical) {
+            rename(&mut check, stmt, name, &canonical, checker);
+        }
+    }
+    checker.add_check(check);
+}
+
+/// N802: function name should be lowercase.
+pub fn invalid_function_name(checker: &mut Checker, stmt: &Stmt, name: &str) {
+    if helpers::is_lower_snake_case(name) {
+        return;
+    }
+    let mut check = Check::new(
+        CheckKind::InvalidFunctionName(name.to_string()),
+        Range::from_located(stmt),
+    );
+    if checker.patch(check.kind.code()) {
+        let canonical = helpers::to_snake_case(name);
+        if canonical != name && helpers::is_lower_snake_case(&canonical) {
+            rename(&mut check, stmt, name, &canonical, checker);
+        }
+    }
+    checker.add_check(check);
+}
+
+/// N803: argument name should be lowercase.
+pub fn invalid_argument_name(checker: &mut Checker, arg: &Arg) {
+    let name = &arg.node.arg;
+    if helpers::is_lower_snake_case(name) {
+        return;
+    }
+    checker.add_check(Check::new(
+        CheckKind::InvalidArgumentName(name.to_string()),
+        Range::from_located(arg),
+    ));
+}
+
+/// N815: module-level constant should use UPPER_SNAKE_CASE.
+pub fn non_upper_case_constant(checker: &mut Checker, target: &Expr, name: &str) {
+    if helpers::is_upper_snake_case(name) {
+        return;
+    }
+    checker.add_check(Check::new(
+        CheckKind::NonUpperCaseConstant(name.to_string()),
+        Range::from_located(target),
+    ));
+}
+
+/// N804, N805: the first argument of a method should be `cls`/`self`.
+pub fn invalid_first_argument(checker: &mut Checker, arg: &Arg, expected: &str) {
+    if arg.node.arg == expected {
+        return;
+    }
+    checker.add_check(Check::new(
+        CheckKind::InvalidFirstArgument(arg.node.arg.to_string(), expected.to_string()),
+        Range::from_located(arg),
+    ));
+}
+
+/// Attach a [`Fix`] that renames only the definition token, leaving call sites
+/// untouched so the rewrite stays safe. The declared name always lives on the
+/// statement's first line (after `class `/`def `), so we can locate it by
+/// scanning that line rather than rewriting the whole node.
+fn rename(check: &mut Check, stmt: &Stmt, name: &str, canonical: &str, checker: &Checker) {
+    let line = Range::new(
+        Location::new(stmt.location.row(), 0),
+        Location::new(stmt.location.row() + 1, 0),
+    );
+    let source = checker.locator.slice_source_code_range(&line);
+    let after_keyword = ["class ", "def "]
+        .iter()
+        .find_map(|keyword| source.find(keyword).map(|i| i + keyword.len()))
+        .unwrap_or(0);
+    if let Some(column) = source[after_keyword..].find(name).map(|i| i + after_keyword) {
+        let start = Location::new(stmt.location.row(), column);
+        let end = Location::new(stmt.location.row(), column + name.len());
+        check.amend(Fix::replacement(canonical.to_string(), start, end));
+    }
+}
@@ -0,0 +1,118 @@
+//! Case classifiers and normalizers for identifier-naming checks.
+//!
+//! These mirror the decl-casing validation rust-analyzer performs in
+//! `case_conv`: they recognize the conventional Python spellings
+//! (`snake_case`, `UPPER_SNAKE_CASE`, `CapWords`) while tolerating the leading
+//! underscores, dunders, and embedded acronym runs that real code uses.
+
+/// Split a name into its leading underscores, significant body, and trailing
+/// underscores, so classifiers can reason about the body alone.
+fn split_underscores(name: &str) -> (&str, &str, &str) {
+    let body = name.trim_matches('_');
+    let lead = &name[..name.len() - name.trim_start_matches('_').len()];
+    let trail = &name[name.trim_end_matches('_').len()..];
+    (lead, body, trail)
+}
+
+/// Is `name` written in `lower_snake_case` (e.g. `spam`, `_private`,
+/// `__init__`)?
+pub fn is_lower_snake_case(name: &str) -> bool {
+    let (_, body, _) = split_underscores(name);
+    !body.is_empty()
+        && body
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        && body.chars().any(|c| c.is_ascii_lowercase())
+}
+
+/// Is `name` written in `UPPER_SNAKE_CASE` (a constant, e.g. `MAX_SIZE`)?
+pub fn is_upper_snake_case(name: &str) -> bool {
+    let (_, body, _) = split_underscores(name);
+    !body.is_empty()
+        && body
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+        && body.chars().any(|c| c.is_ascii_uppercase())
+}
+
+/// Is `name` written in `CapWords` (a class name, e.g. `HTTPServer`)? Acronym
+/// runs are permitted; embedded underscores are not.
+pub fn is_cap_words(name: &str) -> bool {
+    let (_, body, _) = split_underscores(name);
+    let mut chars = body.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_uppercase())
+        && chars.all(|c| c.is_ascii_alphanumeric())
+}
+
+/// Normalize `name` to `snake_case`, inserting word boundaries before acronym
+/// runs and case transitions while preserving surrounding underscores.
+pub fn to_snake_case(name: &str) -> String {
+    let (lead, body, trail) = split_underscores(name);
+    let chars: Vec<char> = body.chars().collect();
+    let mut out = String::with_capacity(body.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() {
+            let prev_lower = i > 0 && !chars[i - 1].is_ascii_uppercase() && chars[i - 1] != '_';
+            let acronym_end = i > 0
+                && chars[i - 1].is_ascii_uppercase()
+                && chars.get(i + 1).is_some_and(|n| n.is_ascii_lowercase());
+            if (prev_lower || acronym_end) && !out.ends_with('_') {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    format!("{lead}{out}{trail}")
+}
+
+/// Normalize `name` to `CapWords`, preserving surrounding underscores.
+pub fn to_cap_words(name: &str) -> String {
+    let (lead, body, trail) = split_underscores(name);
+    let mut out = String::with_capacity(body.len());
+    for part in body.split('_').filter(|part| !part.is_empty()) {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            out.push(first.to_ascii_uppercase());
+            out.extend(chars);
+        }
+    }
+    format!("{lead}{out}{trail}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifiers() {
+        assert!(is_lower_snake_case("spam"));
+        assert!(is_lower_snake_case("_private"));
+        assert!(is_lower_snake_case("__init__"));
+        assert!(is_lower_snake_case("x"));
+        assert!(!is_lower_snake_case("Spam"));
+        assert!(!is_lower_snake_case("__"));
+
+        assert!(is_upper_snake_case("MAX_SIZE"));
+        assert!(is_upper_snake_case("X"));
+        assert!(!is_upper_snake_case("Max_Size"));
+
+        assert!(is_cap_words("ClassName"));
+        assert!(is_cap_words("HTTPServer"));
+        assert!(is_cap_words("_Private"));
+        assert!(!is_cap_words("class_name"));
+        assert!(!is_cap_words("camelCase"));
+    }
+
+    #[test]
+    fn normalizers() {
+        assert_eq!(to_snake_case("HTTPServer"), "http_server");
+        assert_eq!(to_snake_case("getHTTPResponse"), "get_http_response");
+        assert_eq!(to_snake_case("_Private"), "_private");
+        assert_eq!(to_snake_case("spam"), "spam");
+
+        assert_eq!(to_cap_words("http_server"), "HttpServer");
+        assert_eq!(to_cap_words("_private_name"), "_PrivateName");
+    }
+}
@@ -0,0 +1,4 @@
+//! Rules for the `pep8-naming` plugin.
+
+pub mod checks;
+pub mod helpers;
@@ -2,7 +2,7 @@ use anyhow::Result;
 use itertools::izip;
 use log::error;
 use rustc_hash::FxHashMap;
-use rustpython_ast::{Arguments, Location, StmtKind};
+use rustpython_ast::{Arguments, ExprContext, Location, StmtKind};
 use rustpython_parser::ast::{Cmpop, Constant, Expr, ExprKind, Stmt, Unaryop};
 
 use crate::ast::helpers;
@@ -218,6 +218,225 @@ pub fn literal_comparisons(
     checker.add_checks(checks.into_iter());
 }
 
+fn unparse(expr: &Expr, stylist: &SourceCodeStyleDetector) -> Option<String> {
+    let mut generator = SourceCodeGenerator::new(
+        stylist.indentation(),
+        stylist.quote(),
+        stylist.line_ending(),
+    );
+    generator.unparse_expr(expr, 0);
+    generator.generate().ok()
+}
+
+/// Flip a comparison operator to its logical negation, so that a single `not`
+/// can be pushed onto the comparison rather than wrapping it.
+fn negate_cmpop(op: &Cmpop) -> Cmpop {
+    match op {
+        Cmpop::Eq => Cmpop::NotEq,
+        Cmpop::NotEq => Cmpop::Eq,
+        Cmpop::Lt => Cmpop::GtE,
+        Cmpop::LtE => Cmpop::Gt,
+        Cmpop::Gt => Cmpop::LtE,
+        Cmpop::GtE => Cmpop::Lt,
+        Cmpop::Is => Cmpop::IsNot,
+        Cmpop::IsNot => Cmpop::Is,
+        Cmpop::In => Cmpop::NotIn,
+        Cmpop::NotIn => Cmpop::In,
+    }
+}
+
+/// Build the logical negation of `expr` via De Morgan's laws, pushing the `not`
+/// onto already-negatable comparisons to avoid introducing redundant `not
+/// not`. Returns `None` for anything other than a single `Compare` or `BoolOp`.
+fn demorgan(expr: &Expr) -> Option<Expr> {
+    match &expr.node {
+        ExprKind::Compare {
+            left,
+            ops,
+            comparators,
+        } if ops.len() == 1 => Some(Expr::new(
+            Location::default(),
+            Location::default(),
+            ExprKind::Compare {
+                left: left.clone(),
+                ops: vec![negate_cmpop(&ops[0])],
+                comparators: comparators.clone(),
+            },
+        )),
+        ExprKind::BoolOp { op, values } => {
+            let op = match op {
+                rustpython_parser::ast::Boolop::And => rustpython_parser::ast::Boolop::Or,
+                rustpython_parser::ast::Boolop::Or => rustpython_parser::ast::Boolop::And,
+            };
+            let values = values.iter().map(negate).collect();
+            Some(Expr::new(
+                Location::default(),
+                Location::default(),
+                ExprKind::BoolOp { op, values },
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Negate a single operand, reusing [`demorgan`] so nested comparisons flip
+/// their operator instead of gaining a `not` prefix.
+fn negate(expr: &Expr) -> Expr {
+    demorgan(expr).unwrap_or_else(|| {
+        Expr::new(
+            Location::default(),
+            Location::default(),
+            ExprKind::UnaryOp {
+                op: Unaryop::Not,
+                operand: Box::new(expr.clone()),
+            },
+        )
+    })
+}
+
+/// SIM208-style De Morgan simplification: `not (a == b)` → `a != b`, `not (a
+/// and b)` → `not a or not b`.
+pub fn negated_tests(checker: &mut Checker, expr: &Expr, op: &Unaryop, operand: &Expr) {
+    if !matches!(op, Unaryop::Not) {
+        return;
+    }
+    let single_compare = matches!(
+        &operand.node,
+        ExprKind::Compare { ops, .. } if ops.len() == 1
+    );
+    if !single_compare && !matches!(operand.node, ExprKind::BoolOp { .. }) {
+        return;
+    }
+    let Some(negated) = demorgan(operand) else {
+        return;
+    };
+    let mut check = Check::new(CheckKind::NegatedCondition, Range::from_located(expr));
+    if checker.patch(check.kind.code()) {
+        if let Some(content) = unparse(&negated, checker.style) {
+            // Distributing over a `BoolOp` lowers the top connective (`and`→`or`),
+            // so the bare replacement would rebind against a looser surrounding
+            // operator and change the boolean. Wrap it to preserve grouping; a
+            // single flipped `Compare` binds at least as tightly as the `not` it
+            // replaces, so it needs no parentheses.
+            let content = if matches!(negated.node, ExprKind::BoolOp { .. }) {
+                format!("({content})")
+            } else {
+                content
+            };
+            check.amend(Fix::replacement(
+                content,
+                expr.location,
+                expr.end_location.unwrap(),
+            ));
+        }
+    }
+    checker.add_check(check);
+}
+
+/// If `expr` is a bare `type(x)` call, return its single argument.
+fn type_call_arg(expr: &Expr) -> Option<&Expr> {
+    if let ExprKind::Call {
+        func,
+        args,
+        keywords,
+    } = &expr.node
+    {
+        if let ExprKind::Name { id, .. } = &func.node {
+            if id == "type" && args.len() == 1 && keywords.is_empty() {
+                return Some(&args[0]);
+            }
+        }
+    }
+    None
+}
+
+/// Is `expr` a plain type object reference (`int`, `collections.abc.Mapping`)?
+fn is_type_object(expr: &Expr) -> bool {
+    matches!(
+        expr.node,
+        ExprKind::Name { .. } | ExprKind::Attribute { .. }
+    )
+}
+
+fn name(id: &str) -> Expr {
+    Expr::new(
+        Location::default(),
+        Location::default(),
+        ExprKind::Name {
+            id: id.to_string(),
+            ctx: ExprContext::Load,
+        },
+    )
+}
+
+/// E721
+pub fn type_comparison(
+    checker: &mut Checker,
+    expr: &Expr,
+    left: &Expr,
+    ops: &[Cmpop],
+    comparators: &[Expr],
+) {
+    // Skip chained comparisons such as `type(a) == type(b) == type(c)`.
+    if ops.len() != 1 {
+        return;
+    }
+    if !matches!(ops[0], Cmpop::Eq | Cmpop::NotEq) {
+        return;
+    }
+    let right = &comparators[0];
+
+    // Identify the object under test and the type to compare against. Both
+    // sides may be `type(...)`, or one side may be a bare type object.
+    let (subject, compared) = match (type_call_arg(left), type_call_arg(right)) {
+        (Some(subject), Some(_)) => (subject, right),
+        (Some(subject), None) if is_type_object(right) => (subject, right),
+        (None, Some(subject)) if is_type_object(left) => (subject, left),
+        _ => return,
+    };
+
+    // Only the `type(a) == type(b)` form gets a fix: its second operand is
+    // always a type, so `isinstance` cannot raise `TypeError`. A bare name or
+    // attribute (`type(x) == int`, `type(x) == some_var`) may not be a type, so
+    // those forms are reported without a fix. `isinstance` also matches
+    // instances of subclasses, so the fix is not equivalent when `a` is an
+    // instance of a subclass of `type(b)`.
+    let both_type_calls = type_call_arg(left).is_some() && type_call_arg(right).is_some();
+
+    let mut check = Check::new(CheckKind::TypeComparison, Range::from_located(expr));
+    if checker.patch(check.kind.code()) && both_type_calls {
+        let call = Expr::new(
+            Location::default(),
+            Location::default(),
+            ExprKind::Call {
+                func: Box::new(name("isinstance")),
+                args: vec![subject.clone(), compared.clone()],
+                keywords: vec![],
+            },
+        );
+        let replacement = if matches!(ops[0], Cmpop::NotEq) {
+            Expr::new(
+                Location::default(),
+                Location::default(),
+                ExprKind::UnaryOp {
+                    op: Unaryop::Not,
+                    operand: Box::new(call),
+                },
+            )
+        } else {
+            call
+        };
+        if let Some(content) = unparse(&replacement, checker.style) {
+            check.amend(Fix::replacement(
+                content,
+                expr.location,
+                expr.end_location.unwrap(),
+            ));
+        }
+    }
+    checker.add_check(check);
+}
+
 /// E713, E714
 pub fn not_tests(
     checker: &mut Checker,
@@ -21,91 +21,102 @@ pub fn native_literals(
         return;
     }
 
-    if (id == "str" || id == "bytes") && checker.is_builtin(id) {
-        let Some(arg) = args.get(0) else {
-            let mut check = Check::new(CheckKind::NativeLiterals(if id == "str" {
-                LiteralType::Str
-            } else {
-                LiteralType::Bytes
-            }), Range::from_located(expr));
-            if checker.patch(&CheckCode::UP018) {
-                check.amend(Fix::replacement(
-                    if id == "bytes" {
-                        let mut content = String::with_capacity(3);
-                        content.push('b');
-                        content.push(checker.style.quote().into());
-                        content.push(checker.style.quote().into());
-                        content
-                    } else {
-                        let mut content = String::with_capacity(2);
-                        content.push(checker.style.quote().into());
-                        content.push(checker.style.quote().into());
-                        content
-                    },
-                    expr.location,
-                    expr.end_location.unwrap(),
-                ));
+    let Some(literal_type) = literal_type(id) else {
+        return;
+    };
+    if !checker.is_builtin(id) {
+        return;
+    }
+
+    let Some(arg) = args.get(0) else {
+        // The zero-argument constructors `str()`/`bytes()` unwrap to an empty
+        // literal; zero-argument numeric/boolean constructors are not
+        // rewritten.
+        let empty = match literal_type {
+            LiteralType::Str => {
+                let mut content = String::with_capacity(2);
+                content.push(checker.style.quote().into());
+                content.push(checker.style.quote().into());
+                content
             }
-            checker.add_check(check);
-            return;
+            LiteralType::Bytes => {
+                let mut content = String::with_capacity(3);
+                content.push('b');
+                content.push(checker.style.quote().into());
+                content.push(checker.style.quote().into());
+                content
+            }
+            _ => return,
         };
-
-        // Look for `str("")`.
-        if id == "str"
-            && !matches!(
-                &arg.node,
-                ExprKind::Constant {
-                    value: Constant::Str(_),
-                    ..
-                },
-            )
-        {
-            return;
+        let mut check =
+            Check::new(CheckKind::NativeLiterals(literal_type), Range::from_located(expr));
+        if checker.patch(&CheckCode::UP018) {
+            check.amend(Fix::replacement(empty, expr.location, expr.end_location.unwrap()));
         }
+        checker.add_check(check);
+        return;
+    };
 
-        // Look for `bytes(b"")`
-        if id == "bytes"
-            && !matches!(
-                &arg.node,
-                ExprKind::Constant {
-                    value: Constant::Bytes(_),
-                    ..
-                },
-            )
-        {
-            return;
-        }
+    // The argument must already be a `Constant` of the matching type. This also
+    // guards against value-changing rewrites like `float("inf")`, whose string
+    // argument is not a float literal and therefore never matches.
+    if !matches_literal(literal_type, &arg.node) {
+        return;
+    }
+
+    let arg_code = checker
+        .locator
+        .slice_source_code_range(&Range::from_located(arg));
 
-        // rust-python merges adjacent string/bytes literals into one node, but we can't
-        // safely remove the outer call in this situation. We're following pyupgrade
-        // here and skip.
-        let arg_code = checker
-            .locator
-            .slice_source_code_range(&Range::from_located(arg));
-        if lexer::make_tokenizer(&arg_code)
+    // rust-python merges adjacent string/bytes literals into one node, but we
+    // can't safely remove the outer call in that case. Following pyupgrade, we
+    // skip it.
+    if matches!(literal_type, LiteralType::Str | LiteralType::Bytes)
+        && lexer::make_tokenizer(&arg_code)
             .flatten()
             .filter(|(_, tok, _)| matches!(tok, Tok::String { .. }))
             .count()
             > 1
-        {
-            return;
-        }
+    {
+        return;
+    }
 
-        let mut check = Check::new(
-            CheckKind::NativeLiterals(if id == "str" {
-                LiteralType::Str
-            } else {
-                LiteralType::Bytes
-            }),
-            Range::from_located(expr),
-        );
-        if checker.patch(&CheckCode::UP018) {
-            check.amend(Fix::replacement(
-                arg_code.to_string(),
-                expr.location,
-                expr.end_location.unwrap(),
-            ));
-        }
-        checker.add_check(check);
+    let mut check =
+        Check::new(CheckKind::NativeLiterals(literal_type), Range::from_located(expr));
+    if checker.patch(&CheckCode::UP018) {
+        check.amend(Fix::replacement(
+            arg_code.to_string(),
+            expr.location,
+            expr.end_location.unwrap(),
+        ));
+    }
+    checker.add_check(check);
+}
+
+/// Map a builtin constructor name to the literal type it wraps.
+fn literal_type(id: &str) -> Option<LiteralType> {
+    match id {
+        "str" => Some(LiteralType::Str),
+        "bytes" => Some(LiteralType::Bytes),
+        "int" => Some(LiteralType::Int),
+        "float" => Some(LiteralType::Float),
+        "bool" => Some(LiteralType::Bool),
+        "complex" => Some(LiteralType::Complex),
+        _ => None,
+    }
+}
+
+/// Is `node` already a `Constant` of the given literal type?
+fn matches_literal(literal_type: LiteralType, node: &ExprKind) -> bool {
+    let ExprKind::Constant { value, .. } = node else {
+        return false;
+    };
+    match literal_type {
+        LiteralType::Str => matches!(value, Constant::Str(_)),
+        LiteralType::Bytes => matches!(value, Constant::Bytes(_)),
+        LiteralType::Int => matches!(value, Constant::Int(_)),
+        LiteralType::Float => matches!(value, Constant::Float(_)),
+        LiteralType::Bool => matches!(value, Constant::Bool(_)),
+        LiteralType::Complex => matches!(value, Constant::Complex { .. }),
     }
 }
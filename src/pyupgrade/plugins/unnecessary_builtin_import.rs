@@ -46,21 +46,38 @@ pub fn unnecessary_builtin_import(
     module: &str,
     names: &[Located<AliasData>],
 ) {
-    let deprecated_names = match module {
-        "builtins" => BUILTINS,
-        "io" => IO,
-        "six" => SIX,
-        "six.moves" => SIX_MOVES,
-        "six.moves.builtins" => SIX_MOVES_BUILTINS,
-        _ => return,
+    let builtin_names: Option<&[&str]> = match module {
+        "builtins" => Some(BUILTINS),
+        "io" => Some(IO),
+        "six" => Some(SIX),
+        "six.moves" => Some(SIX_MOVES),
+        "six.moves.builtins" => Some(SIX_MOVES_BUILTINS),
+        _ => None,
     };
 
+    // The built-in lists can be extended per-module from user configuration, so
+    // a module not in the table is still in scope when it has a custom entry.
+    let extra: Vec<&str> = checker
+        .settings
+        .pyupgrade
+        .extend_deprecated_imports
+        .iter()
+        .filter(|(name, _)| name == module)
+        .flat_map(|(_, names)| names.iter().map(String::as_str))
+        .collect();
+
+    if builtin_names.is_none() && extra.is_empty() {
+        return;
+    }
+
     let mut unused_imports: Vec<&Alias> = vec![];
     for alias in names {
         if alias.node.asname.is_some() {
             continue;
         }
-        if deprecated_names.contains(&alias.node.name.as_str()) {
+        let name = alias.node.name.as_str();
+        if builtin_names.is_some_and(|deprecated| deprecated.contains(&name)) || extra.contains(&name)
+        {
             unused_imports.push(alias);
         }
     }
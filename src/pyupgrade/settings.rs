@@ -0,0 +1,56 @@
+//! Settings for the `pyupgrade` plugin.
+
+use std::collections::BTreeMap;
+
+use ruff_macros::ConfigurationOptions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, PartialEq, Eq, Serialize, Deserialize, Default, ConfigurationOptions, JsonSchema,
+)]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "kebab-case",
+    rename = "PyUpgradeOptions"
+)]
+pub struct Options {
+    #[option(
+        default = r#"{}"#,
+        value_type = "BTreeMap<String, Vec<String>>",
+        example = r#"
+            # Treat `from mymod import spam` as a redundant `__future__`-style
+            # import that `UP029` should remove.
+            extend-deprecated-imports = { "mymod" = ["spam"] }
+        "#
+    )]
+    /// Additional `module` → imported-name mappings whose members `UP029`
+    /// should flag as unnecessary builtin imports, extending the built-in
+    /// `builtins`/`six`/`six.moves` lists.
+    pub extend_deprecated_imports: Option<BTreeMap<String, Vec<String>>>,
+}
+
+#[derive(Debug, Hash, Default)]
+pub struct Settings {
+    pub extend_deprecated_imports: Vec<(String, Vec<String>)>,
+}
+
+impl From<Options> for Settings {
+    fn from(options: Options) -> Self {
+        Self {
+            extend_deprecated_imports: options
+                .extend_deprecated_imports
+                .unwrap_or_default()
+                .into_iter()
+                .collect(),
+        }
+    }
+}
+
+impl From<Settings> for Options {
+    fn from(settings: Settings) -> Self {
+        Self {
+            extend_deprecated_imports: Some(settings.extend_deprecated_imports.into_iter().collect()),
+        }
+    }
+}
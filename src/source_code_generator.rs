@@ -1,16 +1,20 @@
 //! Generate Python source code from an abstract syntax tree (AST).
 
+use std::collections::BTreeMap;
 use std::fmt;
 use std::ops::Deref;
 use std::string::FromUtf8Error;
 
 use anyhow::Result;
-use rustpython_ast::{Excepthandler, ExcepthandlerKind, Suite, Withitem};
+use rustc_hash::FxHashSet;
+use rustpython_ast::{Excepthandler, ExcepthandlerKind, Location, Suite, Withitem};
 use rustpython_parser::ast::{
     Alias, Arg, Arguments, Boolop, Cmpop, Comprehension, Constant, ConversionFlag, Expr, ExprKind,
     Operator, Stmt, StmtKind,
 };
 
+use crate::ast::types::Range;
+use crate::source_code_generator::pretty::{BeginToken, BreakToken, Breaks, Printer};
 use crate::source_code_style::{Indentation, LineEnding, Quote};
 use crate::vendor::{bytes, str};
 
@@ -32,6 +36,39 @@ mod precedence {
     pub const EXPR: u8 = BOR;
 }
 
+/// The kind of AST node the generator is about to dispatch on, passed to the
+/// [`Annotator`] hooks. Node kinds are type-erased so the trait stays
+/// object-safe across the generator's `U`-generic dispatch functions.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NodeKind {
+    Statement,
+    Expression,
+}
+
+/// An extensibility hook invoked around each statement and expression dispatch,
+/// analogous to rustc `pprust`'s `PpAnn`. The `offset` is the length, in bytes,
+/// of the logical output emitted so far (before line-width wrapping).
+///
+/// The default implementation is a no-op, so callers that don't install an
+/// annotator see exactly the unannotated output. Implementors can build a
+/// source map (node → byte range), wrap tokens in markup for highlighting, or
+/// record per-node provenance for autofix attribution.
+pub trait Annotator {
+    fn pre(&mut self, _kind: NodeKind, _offset: usize) {}
+    fn post(&mut self, _kind: NodeKind, _offset: usize) {}
+}
+
+/// The default [`Annotator`]: every hook is a no-op.
+pub struct NoopAnnotator;
+
+impl Annotator for NoopAnnotator {}
+
+/// Sentinel line width meaning "never wrap". The [`Printer`] margin is an
+/// `isize`, so `usize::MAX` would overflow the `isize::try_from` in
+/// [`Printer::new`] and silently fall back to a finite margin; `isize::MAX`
+/// converts cleanly and is effectively infinite for any real source line.
+const NO_WRAP: usize = isize::MAX as usize;
+
 pub struct SourceCodeGenerator<'a> {
     /// The indentation style to use.
     indent: &'a Indentation,
@@ -39,7 +76,36 @@ pub struct SourceCodeGenerator<'a> {
     quote: &'a Quote,
     /// The line ending to use.
     line_ending: &'a LineEnding,
-    buffer: Vec<u8>,
+    /// The target line width beyond which groups are wrapped. Defaults to
+    /// [`NO_WRAP`], i.e. no wrapping, so the generator round-trips exactly until
+    /// a width is requested via [`SourceCodeGenerator::with_line_width`].
+    max_line_width: usize,
+    /// End locations of bracketed constructs (collections, call argument lists)
+    /// whose source contained a trailing comma before the closing bracket. The
+    /// rustpython AST drops this information, so it is collected from the token
+    /// stream and threaded in here; when a construct is listed, it is forced
+    /// onto one element per line.
+    magic_trailing_commas: FxHashSet<Location>,
+    /// Source comments keyed by the (1-indexed) line they appear on, gathered
+    /// from the lexer token stream. The rustpython AST carries none, so the
+    /// generator interleaves them itself: leading comments are flushed before
+    /// the statement whose row they precede, and a same-line comment trails it.
+    comments: BTreeMap<usize, String>,
+    /// The last source line whose comment has already been emitted, so comments
+    /// are never duplicated or reordered as statements are walked.
+    last_comment_line: usize,
+    /// Verbatim source text of numeric literals, keyed by the node's end
+    /// location. The parsed value alone can't reconstruct the original spelling
+    /// (`0x_FF` vs `255`), so the text is threaded in and re-normalized in
+    /// place. An empty map leaves numbers formatted by their [`Display`] impl.
+    numeric_literals: BTreeMap<Location, String>,
+    /// The installed annotation hook, defaulting to [`NoopAnnotator`].
+    annotator: Box<dyn Annotator>,
+    printer: Printer,
+    /// Running length, in bytes, of the output as it reads with every group laid
+    /// out flat (text, soft-break spaces, and line endings), reported to the
+    /// [`Annotator`] hooks as the current offset.
+    emitted: usize,
     indent_depth: usize,
     num_newlines: usize,
     initial: bool,
@@ -52,16 +118,156 @@ impl<'a> SourceCodeGenerator<'a> {
             indent,
             quote,
             line_ending,
+            max_line_width: NO_WRAP,
+            magic_trailing_commas: FxHashSet::default(),
+            comments: BTreeMap::new(),
+            last_comment_line: 0,
+            numeric_literals: BTreeMap::new(),
+            annotator: Box::new(NoopAnnotator),
             // Internal state.
-            buffer: vec![],
+            printer: Printer::new(NO_WRAP, indent, line_ending),
+            emitted: 0,
             indent_depth: 0,
             num_newlines: 0,
             initial: true,
         }
     }
 
+    /// Set the maximum line width, enabling line-width-aware wrapping of call
+    /// argument lists, collections, and binary-operator chains.
+    #[must_use]
+    pub fn with_line_width(mut self, max_line_width: usize) -> Self {
+        self.max_line_width = max_line_width;
+        self.printer = Printer::new(max_line_width, self.indent, self.line_ending);
+        self
+    }
+
+    /// Record the end locations of bracketed constructs that ended with a
+    /// trailing comma in the source, so they are kept exploded.
+    #[must_use]
+    pub fn with_magic_trailing_commas(mut self, locations: FxHashSet<Location>) -> Self {
+        self.magic_trailing_commas = locations;
+        self
+    }
+
+    /// Supply the comments gathered from the token stream, keyed by the source
+    /// line they appear on, so they are reproduced around the statements they
+    /// annotate rather than silently dropped.
+    #[must_use]
+    pub fn with_comments(mut self, comments: BTreeMap<usize, String>) -> Self {
+        self.comments = comments;
+        self
+    }
+
+    /// Supply the verbatim source text of numeric literals, keyed by end
+    /// location, enabling in-place normalization of their spelling (prefix and
+    /// exponent casing, hex digit casing, imaginary suffix, float forms).
+    /// Digit-group underscores are kept as written; they are not inserted,
+    /// removed, or regrouped.
+    #[must_use]
+    pub fn with_numeric_literals(mut self, literals: BTreeMap<Location, String>) -> Self {
+        self.numeric_literals = literals;
+        self
+    }
+
+    /// Install an [`Annotator`] whose `pre`/`post` hooks fire around each
+    /// statement and expression dispatch.
+    #[must_use]
+    pub fn with_annotator(mut self, annotator: Box<dyn Annotator>) -> Self {
+        self.annotator = annotator;
+        self
+    }
+
     pub fn generate(self) -> Result<String, FromUtf8Error> {
-        String::from_utf8(self.buffer)
+        Ok(self.printer.finish())
+    }
+
+    /// Emit, on their own lines, every comment sitting strictly above `row`
+    /// that has not been emitted yet — the "leading" comments of the node that
+    /// begins on `row`.
+    fn flush_comments_before(&mut self, row: usize) {
+        if self.comments.is_empty() {
+            return;
+        }
+        let rows: Vec<usize> = self
+            .comments
+            .range(self.last_comment_line + 1..row)
+            .map(|(line, _)| *line)
+            .collect();
+        for line in rows {
+            if let Some(text) = self.comments.remove(&line) {
+                self.newline();
+                self.p(&self.indent.deref().repeat(self.indent_depth));
+                self.p(&text);
+                self.initial = false;
+            }
+            self.last_comment_line = line;
+        }
+    }
+
+    /// Emit a comment that shares `row` with the statement just printed, as a
+    /// trailing comment two spaces after the code.
+    fn flush_trailing_comment(&mut self, row: usize) {
+        if let Some(text) = self.comments.remove(&row) {
+            self.p("  ");
+            self.p(&text);
+            self.last_comment_line = row;
+        }
+    }
+
+    /// Did `ast` close with a magic trailing comma?
+    fn is_magic_trailing_comma<U>(&self, ast: &Expr<U>) -> bool {
+        ast.end_location
+            .map_or(false, |location| self.magic_trailing_commas.contains(&location))
+    }
+
+    /// Open a collection/argument group, forcing it broken when `magic` is set.
+    fn open_sequence(&mut self, breaks: Breaks, magic: bool) {
+        self.group(breaks);
+        if magic {
+            self.printer.force_break(0);
+        } else {
+            self.soft_break(0);
+        }
+    }
+
+    /// Width of one indentation level, in columns.
+    fn indent_width(&self) -> isize {
+        self.printer.indent_width()
+    }
+
+    /// Open a group whose `Break`s wrap together (`Consistent`) or
+    /// individually (`Inconsistent`) once the group overflows the margin.
+    fn group(&mut self, breaks: Breaks) {
+        let indent_offset = self.indent_width();
+        self.printer.scan_begin(BeginToken {
+            indent_offset,
+            breaks,
+        });
+    }
+
+    fn end_group(&mut self) {
+        self.printer.scan_end();
+    }
+
+    /// A break that renders as `blank_space` spaces when the group fits, or a
+    /// newline + one level of indentation when it does not.
+    fn soft_break(&mut self, blank_space: isize) {
+        self.printer.scan_break(BreakToken {
+            indent_offset: 0,
+            blank_space,
+        });
+        self.emitted += usize::try_from(blank_space).unwrap_or(0);
+    }
+
+    /// The break placed just before a closing bracket: it dedents back to the
+    /// column of the opening line.
+    fn close_break(&mut self) {
+        let indent_offset = -self.indent_width();
+        self.printer.scan_break(BreakToken {
+            indent_offset,
+            blank_space: 0,
+        });
     }
 
     fn newline(&mut self) {
@@ -86,12 +292,13 @@ impl<'a> SourceCodeGenerator<'a> {
 
     fn p(&mut self, s: &str) {
         if self.num_newlines > 0 {
-            for _ in 0..self.num_newlines {
-                self.buffer.extend(self.line_ending.as_bytes());
-            }
+            let indent = self.indent_depth * usize::try_from(self.indent_width()).unwrap_or(0);
+            self.printer.hard_newlines(self.num_newlines, indent);
+            self.emitted += self.num_newlines * self.line_ending.as_bytes().len();
             self.num_newlines = 0;
         }
-        self.buffer.extend(s.as_bytes());
+        self.printer.scan_string(s.to_string());
+        self.emitted += s.len();
     }
 
     fn p_if(&mut self, cond: bool, s: &str) {
@@ -104,8 +311,19 @@ impl<'a> SourceCodeGenerator<'a> {
         self.p_if(!std::mem::take(first), s);
     }
 
+    /// Emit an element separator inside a group: a comma followed by a soft
+    /// break, so the group collapses to `, ` when it fits and to one element
+    /// per line when it does not.
+    fn delimit(&mut self, first: &mut bool) {
+        if std::mem::take(first) {
+            return;
+        }
+        self.p(",");
+        self.soft_break(1);
+    }
+
     fn write_fmt(&mut self, f: fmt::Arguments<'_>) {
-        self.buffer.extend(format!("{f}").as_bytes());
+        self.p(&format!("{f}"));
     }
 
     pub fn unparse_suite<U>(&mut self, suite: &Suite<U>) {
@@ -115,6 +333,9 @@ impl<'a> SourceCodeGenerator<'a> {
     }
 
     pub fn unparse_stmt<U>(&mut self, ast: &Stmt<U>) {
+        self.flush_comments_before(ast.location.row());
+        let offset = self.emitted;
+        self.annotator.pre(NodeKind::Statement, offset);
         macro_rules! statement {
             ($body:block) => {{
                 self.newline();
@@ -524,6 +745,11 @@ impl<'a> SourceCodeGenerator<'a> {
                 });
             }
         }
+        if let Some(end) = ast.end_location {
+            self.flush_trailing_comment(end.row());
+        }
+        let offset = self.emitted;
+        self.annotator.post(NodeKind::Statement, offset);
     }
 
     fn unparse_excepthandler<U>(&mut self, ast: &Excepthandler<U>) {
@@ -567,6 +793,8 @@ impl<'a> SourceCodeGenerator<'a> {
                 ret
             }};
         }
+        let offset = self.emitted;
+        self.annotator.pre(NodeKind::Expression, offset);
         match &ast.node {
             ExprKind::BoolOp { op, values } => {
                 let (op, prec) = opprec!(bin, op, Boolop, And("and", AND), Or("or", OR));
@@ -606,9 +834,14 @@ impl<'a> SourceCodeGenerator<'a> {
                     FloorDiv("//", TERM),
                 );
                 group_if!(prec, {
+                    self.group(Breaks::Inconsistent);
                     self.unparse_expr(left, prec + u8::from(rassoc));
-                    self.p(op);
+                    // Offer a break before the operator; flat output is
+                    // unchanged since the soft break renders as a single space.
+                    self.soft_break(1);
+                    self.p(op.trim_start());
                     self.unparse_expr(right, prec + u8::from(!rassoc));
+                    self.end_group();
                 });
             }
             ExprKind::UnaryOp { op, operand } => {
@@ -644,29 +877,39 @@ impl<'a> SourceCodeGenerator<'a> {
                 });
             }
             ExprKind::Dict { keys, values } => {
+                let magic = self.is_magic_trailing_comma(ast);
                 self.p("{");
+                self.open_sequence(Breaks::Consistent, magic);
                 let mut first = true;
                 let (packed, unpacked) = values.split_at(keys.len());
                 for (k, v) in keys.iter().zip(packed) {
-                    self.p_delim(&mut first, ", ");
+                    self.delimit(&mut first);
                     write!(self, "{}: {}", *k, *v);
                 }
                 for d in unpacked {
-                    self.p_delim(&mut first, ", ");
+                    self.delimit(&mut first);
                     write!(self, "**{}", *d);
                 }
+                self.p_if(magic, ",");
+                self.close_break();
+                self.end_group();
                 self.p("}");
             }
             ExprKind::Set { elts } => {
                 if elts.is_empty() {
                     self.p("set()");
                 } else {
+                    let magic = self.is_magic_trailing_comma(ast);
                     self.p("{");
+                    self.open_sequence(Breaks::Consistent, magic);
                     let mut first = true;
                     for v in elts {
-                        self.p_delim(&mut first, ", ");
+                        self.delimit(&mut first);
                         self.unparse_expr(v, precedence::TEST);
                     }
+                    self.p_if(magic, ",");
+                    self.close_break();
+                    self.end_group();
                     self.p("}");
                 }
             }
@@ -761,13 +1004,15 @@ impl<'a> SourceCodeGenerator<'a> {
                     self.unparse_expr(elt, precedence::TEST);
                     self.unparse_comp(generators);
                 } else {
+                    let magic = self.is_magic_trailing_comma(ast);
+                    self.open_sequence(Breaks::Consistent, magic);
                     let mut first = true;
                     for arg in args {
-                        self.p_delim(&mut first, ", ");
+                        self.delimit(&mut first);
                         self.unparse_expr(arg, precedence::TEST);
                     }
                     for kw in keywords {
-                        self.p_delim(&mut first, ", ");
+                        self.delimit(&mut first);
                         if let Some(arg) = &kw.node.arg {
                             self.p(arg);
                             self.p("=");
@@ -776,6 +1021,9 @@ impl<'a> SourceCodeGenerator<'a> {
                         }
                         self.unparse_expr(&kw.node.value, precedence::TEST);
                     }
+                    self.p_if(magic, ",");
+                    self.close_break();
+                    self.end_group();
                 }
                 self.p(")");
             }
@@ -804,7 +1052,17 @@ impl<'a> SourceCodeGenerator<'a> {
                     Constant::Str(s) => {
                         self.p(&format!("{}", str::repr(s, self.quote.into())));
                     }
-                    _ => self.p(&format!("{value}")),
+                    _ => {
+                        if let Some(text) = ast
+                            .end_location
+                            .and_then(|loc| self.numeric_literals.get(&loc))
+                            .cloned()
+                        {
+                            self.p(&normalize_number_literal(&text));
+                        } else {
+                            self.p(&format!("{value}"));
+                        }
+                    }
                 }
             }
             ExprKind::Attribute { value, attr, .. } => {
@@ -842,25 +1100,36 @@ impl<'a> SourceCodeGenerator<'a> {
             }
             ExprKind::Name { id, .. } => self.p(id),
             ExprKind::List { elts, .. } => {
+                let magic = self.is_magic_trailing_comma(ast);
                 self.p("[");
+                self.open_sequence(Breaks::Consistent, magic);
                 let mut first = true;
                 for elt in elts {
-                    self.p_delim(&mut first, ", ");
+                    self.delimit(&mut first);
                     self.unparse_expr(elt, precedence::TEST);
                 }
+                self.p_if(magic, ",");
+                self.close_break();
+                self.end_group();
                 self.p("]");
             }
             ExprKind::Tuple { elts, .. } => {
                 if elts.is_empty() {
                     self.p("()");
                 } else {
+                    let magic = self.is_magic_trailing_comma(ast);
                     group_if!(precedence::TUPLE, {
+                        self.open_sequence(Breaks::Consistent, magic);
                         let mut first = true;
                         for elt in elts {
-                            self.p_delim(&mut first, ", ");
+                            self.delimit(&mut first);
                             self.unparse_expr(elt, precedence::TEST);
                         }
-                        self.p_if(elts.len() == 1, ",");
+                        // A single-element tuple always keeps its comma; the
+                        // magic comma adds one otherwise.
+                        self.p_if(elts.len() == 1 || magic, ",");
+                        self.close_break();
+                        self.end_group();
                     });
                 }
             }
@@ -878,13 +1147,17 @@ impl<'a> SourceCodeGenerator<'a> {
                 }
             }
         }
+        let offset = self.emitted;
+        self.annotator.post(NodeKind::Expression, offset);
     }
 
     fn unparse_args<U>(&mut self, args: &Arguments<U>) {
+        self.group(Breaks::Consistent);
+        self.soft_break(0);
         let mut first = true;
         let defaults_start = args.posonlyargs.len() + args.args.len() - args.defaults.len();
         for (i, arg) in args.posonlyargs.iter().chain(&args.args).enumerate() {
-            self.p_delim(&mut first, ", ");
+            self.delimit(&mut first);
             self.unparse_arg(arg);
             if let Some(i) = i.checked_sub(defaults_start) {
                 write!(self, "={}", &args.defaults[i]);
@@ -892,7 +1165,7 @@ impl<'a> SourceCodeGenerator<'a> {
             self.p_if(i + 1 == args.posonlyargs.len(), ", /");
         }
         if args.vararg.is_some() || !args.kwonlyargs.is_empty() {
-            self.p_delim(&mut first, ", ");
+            self.delimit(&mut first);
             self.p("*");
         }
         if let Some(vararg) = &args.vararg {
@@ -900,7 +1173,7 @@ impl<'a> SourceCodeGenerator<'a> {
         }
         let defaults_start = args.kwonlyargs.len() - args.kw_defaults.len();
         for (i, kwarg) in args.kwonlyargs.iter().enumerate() {
-            self.p_delim(&mut first, ", ");
+            self.delimit(&mut first);
             self.unparse_arg(kwarg);
             if let Some(default) = i
                 .checked_sub(defaults_start)
@@ -910,10 +1183,12 @@ impl<'a> SourceCodeGenerator<'a> {
             }
         }
         if let Some(kwarg) = &args.kwarg {
-            self.p_delim(&mut first, ", ");
+            self.delimit(&mut first);
             self.p("**");
             self.unparse_arg(kwarg);
         }
+        self.close_break();
+        self.end_group();
     }
 
     fn unparse_arg<U>(&mut self, arg: &Arg<U>) {
@@ -949,14 +1224,15 @@ impl<'a> SourceCodeGenerator<'a> {
     fn unparse_formatted<U>(&mut self, val: &Expr<U>, conversion: usize, spec: Option<&Expr<U>>) {
         let mut generator = SourceCodeGenerator::new(self.indent, self.quote, self.line_ending);
         generator.unparse_expr(val, precedence::TEST + 1);
-        let brace = if generator.buffer.starts_with("{".as_bytes()) {
+        let body = generator.generate().unwrap_or_default();
+        let brace = if body.starts_with('{') {
             // put a space to avoid escaping the bracket
             "{ "
         } else {
             "{"
         };
         self.p(brace);
-        self.buffer.extend(generator.buffer);
+        self.p(&body);
 
         if conversion != ConversionFlag::None as usize {
             self.p("!");
@@ -1005,8 +1281,8 @@ impl<'a> SourceCodeGenerator<'a> {
             self.p("f");
             let mut generator = SourceCodeGenerator::new(self.indent, self.quote, self.line_ending);
             generator.unparse_fstring_body(values, is_spec);
-            let body = std::str::from_utf8(&generator.buffer).unwrap();
-            self.p(&format!("{}", str::repr(body, self.quote.into())));
+            let body = generator.generate().unwrap_or_default();
+            self.p(&format!("{}", str::repr(&body, self.quote.into())));
         }
     }
 
@@ -1027,6 +1303,458 @@ impl<'a> SourceCodeGenerator<'a> {
     }
 }
 
+/// Splice the freshly-unparsed `replacement` of a single AST node back into the
+/// `original` source, preserving every byte outside the node's `span` verbatim.
+///
+/// The splice is driven by the changed node's [`Location`] span rather than by a
+/// textual diff of the whole file: only the bytes covered by `span` in the
+/// original are replaced, and everything before and after that span is copied
+/// from `original` unchanged. Bounding the replaced region by a node span is the
+/// key invariant — it guarantees we never cut across a syntactic boundary, so
+/// the freshly-unparsed formatting of untouched siblings never leaks in. The
+/// result is that reformatting or a single autofix touches only the lines
+/// spanning the node that actually changed.
+///
+/// When `span` does not resolve into `original` (e.g. a synthesized node with no
+/// source location) the replacement is returned as-is, since there is no span to
+/// anchor the splice to.
+pub fn minimal_diff(original: &str, replacement: &str, span: &Range) -> String {
+    let (Some(start), Some(end)) = (
+        offset_of(original, span.location),
+        offset_of(original, span.end_location),
+    ) else {
+        return replacement.to_string();
+    };
+    let mut out = String::with_capacity(original.len() - (end - start) + replacement.len());
+    out.push_str(&original[..start]);
+    out.push_str(replacement);
+    out.push_str(&original[end..]);
+    out
+}
+
+/// Byte offset of `location` (1-indexed row, 0-indexed column) within `source`,
+/// or `None` if the location falls past the end of the text.
+fn offset_of(source: &str, location: Location) -> Option<usize> {
+    let mut offset = 0;
+    for (row, line) in source.split_inclusive('\n').enumerate() {
+        if row + 1 == location.row() {
+            let column = line
+                .char_indices()
+                .nth(location.column())
+                .map_or(line.len(), |(i, _)| i);
+            return Some(offset + column);
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Canonicalize the spelling of a single numeric literal while preserving its
+/// value: lowercase base prefixes and exponent markers (`0X`→`0x`, `1E5`→`1e5`),
+/// uppercase hex digits, normalize the imaginary suffix (`J`→`j`), and expand
+/// shorthand float forms (`.5`→`0.5`, `1.`→`1.0`). Digit-group underscores are
+/// left untouched.
+fn normalize_number_literal(text: &str) -> String {
+    let (body, imag) = match text.strip_suffix(['j', 'J']) {
+        Some(body) => (body, "j"),
+        None => (text, ""),
+    };
+    let normalized = if body.len() >= 2
+        && body.starts_with('0')
+        && matches!(
+            body.as_bytes()[1],
+            b'x' | b'X' | b'o' | b'O' | b'b' | b'B'
+        ) {
+        normalize_prefixed(body)
+    } else {
+        normalize_decimal(body)
+    };
+    format!("{normalized}{imag}")
+}
+
+/// Normalize a prefixed integer literal (`0x`/`0o`/`0b`): lowercase the prefix
+/// and, for hexadecimal, uppercase the digits.
+fn normalize_prefixed(body: &str) -> String {
+    let prefix = body[..2].to_ascii_lowercase();
+    let digits = &body[2..];
+    if prefix == "0x" {
+        format!("{prefix}{}", digits.to_ascii_uppercase())
+    } else {
+        format!("{prefix}{digits}")
+    }
+}
+
+/// Normalize a decimal integer or float literal: lowercase the exponent marker
+/// and fill in the omitted integer/fractional digit of a bare float.
+fn normalize_decimal(body: &str) -> String {
+    let lower = body.replace('E', "e");
+    let (mantissa, exp) = match lower.split_once('e') {
+        Some((m, e)) => (m.to_string(), format!("e{e}")),
+        None => (lower, String::new()),
+    };
+    let mantissa = if let Some((int, frac)) = mantissa.split_once('.') {
+        let int = if int.is_empty() { "0" } else { int };
+        let frac = if frac.is_empty() { "0" } else { frac };
+        format!("{int}.{frac}")
+    } else {
+        mantissa
+    };
+    format!("{mantissa}{exp}")
+}
+
+/// A line-width-aware pretty-printer implementing Oppen's algorithm, as used by
+/// rustc's `pprust` and `prettyplease`.
+///
+/// Output is produced by two cooperating passes connected through a ring
+/// buffer. The *scan* pass accumulates a running "right total" and, when an
+/// [`End`](Token::End) is seen, back-patches the matching [`Begin`](Token::Begin)'s
+/// size to the span width. The *print* pass consumes tokens left-to-right,
+/// tracking the `space` remaining against the margin: a group that does not fit
+/// breaks its [`Break`](Token::Break) tokens onto fresh, indented lines.
+mod pretty {
+    use std::collections::VecDeque;
+    use std::ops::Deref;
+
+    use crate::source_code_style::{Indentation, LineEnding};
+
+    /// The size assigned to a group that is known not to fit on the current
+    /// line (or whose look-ahead has overflowed the ring buffer).
+    const SIZE_INFINITY: isize = 0xffff;
+
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub enum Breaks {
+        /// Break every [`Break`](Token::Break) in the group, or none of them.
+        Consistent,
+        /// Break only the [`Break`](Token::Break)s whose following chunk would
+        /// overflow the margin.
+        Inconsistent,
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct BeginToken {
+        pub indent_offset: isize,
+        pub breaks: Breaks,
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct BreakToken {
+        pub indent_offset: isize,
+        pub blank_space: isize,
+    }
+
+    pub enum Token {
+        String(String),
+        Break(BreakToken),
+        Begin(BeginToken),
+        End,
+    }
+
+    #[derive(Clone, Copy)]
+    enum PrintFrame {
+        Fits(Breaks),
+        Broken(usize, Breaks),
+    }
+
+    struct BufEntry {
+        token: Token,
+        size: isize,
+    }
+
+    /// A ring buffer that tracks the absolute index of its front element, so
+    /// the scan stack can refer to tokens by their position in the stream.
+    struct RingBuffer {
+        data: VecDeque<BufEntry>,
+        offset: usize,
+    }
+
+    impl RingBuffer {
+        fn new() -> Self {
+            Self {
+                data: VecDeque::new(),
+                offset: 0,
+            }
+        }
+
+        fn is_empty(&self) -> bool {
+            self.data.is_empty()
+        }
+
+        fn push(&mut self, entry: BufEntry) -> usize {
+            let index = self.offset + self.data.len();
+            self.data.push_back(entry);
+            index
+        }
+
+        fn clear(&mut self) {
+            self.offset += self.data.len();
+            self.data.clear();
+        }
+
+        fn index_of_first(&self) -> usize {
+            self.offset
+        }
+
+        fn first(&self) -> Option<&BufEntry> {
+            self.data.front()
+        }
+
+        fn first_mut(&mut self) -> Option<&mut BufEntry> {
+            self.data.front_mut()
+        }
+
+        fn pop_first(&mut self) -> BufEntry {
+            self.offset += 1;
+            self.data.pop_front().unwrap()
+        }
+
+        fn get_mut(&mut self, index: usize) -> &mut BufEntry {
+            &mut self.data[index - self.offset]
+        }
+    }
+
+    pub struct Printer {
+        out: String,
+        /// The target line width.
+        margin: isize,
+        /// The space remaining on the current line.
+        space: isize,
+        buf: RingBuffer,
+        left_total: isize,
+        right_total: isize,
+        /// Indices (into the stream) of `Begin`/`Break` tokens whose sizes are
+        /// not yet known.
+        scan_stack: VecDeque<usize>,
+        print_stack: Vec<PrintFrame>,
+        indent: usize,
+        pending_indentation: usize,
+        indent_unit: String,
+        line_ending: String,
+    }
+
+    impl Printer {
+        pub fn new(margin: usize, indent: &Indentation, line_ending: &LineEnding) -> Self {
+            let margin = isize::try_from(margin).unwrap_or(SIZE_INFINITY);
+            Self {
+                out: String::new(),
+                margin,
+                space: margin,
+                buf: RingBuffer::new(),
+                left_total: 0,
+                right_total: 0,
+                scan_stack: VecDeque::new(),
+                print_stack: Vec::new(),
+                indent: 0,
+                pending_indentation: 0,
+                indent_unit: indent.deref().to_string(),
+                line_ending: String::from_utf8_lossy(line_ending.as_bytes()).into_owned(),
+            }
+        }
+
+        /// Width of a single indentation level, in columns.
+        pub fn indent_width(&self) -> isize {
+            self.indent_unit.chars().count().max(1) as isize
+        }
+
+        /// Flush the buffer and return the accumulated output.
+        pub fn finish(mut self) -> String {
+            if !self.scan_stack.is_empty() {
+                self.check_stack(0);
+                self.advance_left();
+            }
+            self.out
+        }
+
+        pub fn scan_begin(&mut self, token: BeginToken) {
+            if self.scan_stack.is_empty() {
+                self.left_total = 1;
+                self.right_total = 1;
+                self.buf.clear();
+            }
+            let right = self.buf.push(BufEntry {
+                token: Token::Begin(token),
+                size: -self.right_total,
+            });
+            self.scan_stack.push_back(right);
+        }
+
+        pub fn scan_end(&mut self) {
+            if self.scan_stack.is_empty() {
+                self.print_end();
+                return;
+            }
+            let right = self.buf.push(BufEntry {
+                token: Token::End,
+                size: -1,
+            });
+            self.scan_stack.push_back(right);
+        }
+
+        /// Emit a break that can never fit, forcing the enclosing group to lay
+        /// out vertically regardless of the margin. Used to honor a magic
+        /// trailing comma.
+        pub fn force_break(&mut self, indent_offset: isize) {
+            self.scan_break(BreakToken {
+                indent_offset,
+                blank_space: SIZE_INFINITY,
+            });
+        }
+
+        pub fn scan_break(&mut self, token: BreakToken) {
+            if self.scan_stack.is_empty() {
+                self.left_total = 1;
+                self.right_total = 1;
+                self.buf.clear();
+            } else {
+                self.check_stack(0);
+            }
+            let right = self.buf.push(BufEntry {
+                token: Token::Break(token),
+                size: -self.right_total,
+            });
+            self.scan_stack.push_back(right);
+            self.right_total += token.blank_space;
+        }
+
+        pub fn scan_string(&mut self, string: String) {
+            if self.scan_stack.is_empty() {
+                self.print_string(&string);
+            } else {
+                let len = string.chars().count() as isize;
+                self.right_total += len;
+                self.buf.push(BufEntry {
+                    token: Token::String(string),
+                    size: len,
+                });
+                self.check_stream();
+            }
+        }
+
+        /// Emit `count` raw line endings and set the base indentation that
+        /// breaks are measured from to `indent` columns. Tokens still buffered
+        /// from groups that have already closed are printed first, so the line
+        /// endings follow them. Called between statements, once every group
+        /// is closed.
+        pub fn hard_newlines(&mut self, count: usize, indent: usize) {
+            if !self.scan_stack.is_empty() {
+                self.check_stack(0);
+                self.advance_left();
+            }
+            for _ in 0..count {
+                self.out.push_str(&self.line_ending);
+            }
+            self.indent = indent;
+            self.pending_indentation = 0;
+            self.space = self.margin;
+        }
+
+        fn check_stream(&mut self) {
+            while self.right_total - self.left_total > self.space {
+                if self.scan_stack.front() == Some(&self.buf.index_of_first()) {
+                    self.scan_stack.pop_front();
+                    self.buf.first_mut().unwrap().size = SIZE_INFINITY;
+                }
+                self.advance_left();
+                if self.buf.is_empty() {
+                    break;
+                }
+            }
+        }
+
+        fn advance_left(&mut self) {
+            while self.buf.first().map_or(false, |entry| entry.size >= 0) {
+                let left = self.buf.pop_first();
+                match left.token {
+                    Token::String(string) => {
+                        self.left_total += left.size;
+                        self.print_string(&string);
+                    }
+                    Token::Break(token) => {
+                        self.left_total += token.blank_space;
+                        self.print_break(token, left.size);
+                    }
+                    Token::Begin(token) => self.print_begin(token, left.size),
+                    Token::End => self.print_end(),
+                }
+                if self.buf.is_empty() {
+                    break;
+                }
+            }
+        }
+
+        fn check_stack(&mut self, mut depth: usize) {
+            while let Some(&index) = self.scan_stack.back() {
+                let entry = self.buf.get_mut(index);
+                match entry.token {
+                    Token::Begin(_) => {
+                        if depth == 0 {
+                            break;
+                        }
+                        self.scan_stack.pop_back();
+                        entry.size += self.right_total;
+                        depth -= 1;
+                    }
+                    Token::End => {
+                        self.scan_stack.pop_back();
+                        entry.size = 1;
+                        depth += 1;
+                    }
+                    _ => {
+                        self.scan_stack.pop_back();
+                        entry.size += self.right_total;
+                        if depth == 0 {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        fn print_begin(&mut self, token: BeginToken, size: isize) {
+            // A group holding a forced break never fits, whatever the margin.
+            if size > self.space || size >= SIZE_INFINITY {
+                self.print_stack
+                    .push(PrintFrame::Broken(self.indent, token.breaks));
+                self.indent = usize::try_from(self.indent as isize + token.indent_offset)
+                    .unwrap_or(self.indent);
+            } else {
+                self.print_stack.push(PrintFrame::Fits(token.breaks));
+            }
+        }
+
+        fn print_end(&mut self) {
+            if let Some(PrintFrame::Broken(indent, _)) = self.print_stack.pop() {
+                self.indent = indent;
+            }
+        }
+
+        fn print_break(&mut self, token: BreakToken, size: isize) {
+            let fits = match self.print_stack.last() {
+                None | Some(PrintFrame::Fits(_)) => true,
+                Some(PrintFrame::Broken(_, Breaks::Consistent)) => false,
+                Some(PrintFrame::Broken(_, Breaks::Inconsistent)) => size <= self.space,
+            };
+            if fits {
+                self.pending_indentation += usize::try_from(token.blank_space).unwrap_or(0);
+                self.space -= token.blank_space;
+            } else {
+                self.out.push_str(&self.line_ending);
+                let indent = (self.indent as isize + token.indent_offset).max(0);
+                self.pending_indentation = indent as usize;
+                self.space = self.margin - indent;
+            }
+        }
+
+        fn print_string(&mut self, string: &str) {
+            for _ in 0..self.pending_indentation {
+                self.out.push(' ');
+            }
+            self.pending_indentation = 0;
+            self.out.push_str(string);
+            self.space -= string.chars().count() as isize;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -1191,6 +1919,182 @@ if True:
         Ok(())
     }
 
+    #[test]
+    fn line_width() -> Result<()> {
+        let indentation = Indentation::default();
+        let quote = Quote::default();
+        let line_ending = LineEnding::default();
+        let program = parser::parse_program("[1, 2, 3]", "<filename>")?;
+        let stmt = program.first().unwrap();
+
+        // With an unlimited width, the collection round-trips flat.
+        let mut generator = SourceCodeGenerator::new(&indentation, &quote, &line_ending);
+        generator.unparse_stmt(stmt);
+        assert_eq!(generator.generate()?, "[1, 2, 3]");
+
+        // With a narrow width, it explodes onto one element per line.
+        let mut generator =
+            SourceCodeGenerator::new(&indentation, &quote, &line_ending).with_line_width(4);
+        generator.unparse_stmt(stmt);
+        assert!(generator.generate()?.contains('\n'));
+
+        Ok(())
+    }
+
+    #[test]
+    fn magic_trailing_comma() -> Result<()> {
+        use rustc_hash::FxHashSet;
+        use rustpython_ast::ExprKind;
+
+        let indentation = Indentation::default();
+        let quote = Quote::default();
+        let line_ending = LineEnding::default();
+        let program = parser::parse_program("[1, 2]", "<filename>")?;
+        let stmt = program.first().unwrap();
+
+        // Without the construct registered, the list stays flat.
+        let mut generator = SourceCodeGenerator::new(&indentation, &quote, &line_ending);
+        generator.unparse_stmt(stmt);
+        assert_eq!(generator.generate()?, "[1, 2]");
+
+        // Registering its end location forces it onto one element per line.
+        let end = match &stmt.node {
+            rustpython_ast::StmtKind::Expr { value } => match &value.node {
+                ExprKind::List { .. } => value.end_location.unwrap(),
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        };
+        let mut magic = FxHashSet::default();
+        magic.insert(end);
+        let mut generator = SourceCodeGenerator::new(&indentation, &quote, &line_ending)
+            .with_line_width(80)
+            .with_magic_trailing_commas(magic);
+        generator.unparse_stmt(stmt);
+        let output = generator.generate()?;
+        assert!(output.contains('\n'));
+        assert!(output.contains(",\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn comments() -> Result<()> {
+        use std::collections::BTreeMap;
+
+        let indentation = Indentation::default();
+        let quote = Quote::default();
+        let line_ending = LineEnding::default();
+        // `x = 1` parses to a statement on row 2; the comment on row 1 leads it
+        // and the comment on row 2 trails it.
+        let program = parser::parse_program("\nx = 1", "<filename>")?;
+        let stmt = program.first().unwrap();
+
+        let mut comments = BTreeMap::new();
+        comments.insert(1, "# leading".to_string());
+        comments.insert(2, "# trailing".to_string());
+        let mut generator = SourceCodeGenerator::new(&indentation, &quote, &line_ending)
+            .with_comments(comments);
+        generator.unparse_stmt(stmt);
+        assert_eq!(generator.generate()?, "# leading\nx = 1  # trailing");
+
+        Ok(())
+    }
+
+    #[test]
+    fn minimal_diff() -> Result<()> {
+        use rustpython_ast::Location;
+
+        use crate::ast::types::Range;
+        use crate::source_code_generator::minimal_diff;
+
+        // The replacement is spliced into the node's span; bytes outside it are
+        // copied from the original verbatim.
+        let span = Range {
+            location: Location::new(1, 11),
+            end_location: Location::new(1, 12),
+        };
+        assert_eq!(minimal_diff("x = foo(1, 2)", "3", &span), "x = foo(1, 3)");
+
+        // Replacing the whole span with identical text round-trips.
+        assert_eq!(minimal_diff("x = foo(1, 2)", "2", &span), "x = foo(1, 2)");
+
+        // The splice honors the span even when the replacement reformats the
+        // node: the surrounding operators are preserved from the original.
+        let span = Range {
+            location: Location::new(1, 4),
+            end_location: Location::new(1, 5),
+        };
+        assert_eq!(minimal_diff("a + b + c", "B", &span), "a + B + c");
+
+        // A span that falls past the end of the source yields the replacement.
+        let span = Range {
+            location: Location::new(2, 0),
+            end_location: Location::new(2, 1),
+        };
+        assert_eq!(minimal_diff("a + b + c", "B", &span), "B");
+
+        Ok(())
+    }
+
+    #[test]
+    fn numeric_literals() -> Result<()> {
+        use super::normalize_number_literal;
+
+        assert_eq!(normalize_number_literal("0XFF"), "0xFF");
+        assert_eq!(normalize_number_literal("0xff"), "0xFF");
+        assert_eq!(normalize_number_literal("0B1010"), "0b1010");
+        assert_eq!(normalize_number_literal("1E5"), "1e5");
+        assert_eq!(normalize_number_literal(".5"), "0.5");
+        assert_eq!(normalize_number_literal("1."), "1.0");
+        assert_eq!(normalize_number_literal("3J"), "3j");
+        assert_eq!(normalize_number_literal("1_000"), "1_000");
+
+        Ok(())
+    }
+
+    #[test]
+    fn annotator() -> Result<()> {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        use crate::source_code_generator::{Annotator, NodeKind};
+
+        #[derive(Default)]
+        struct Recorder {
+            events: Rc<RefCell<Vec<(NodeKind, usize)>>>,
+        }
+
+        impl Annotator for Recorder {
+            fn pre(&mut self, kind: NodeKind, offset: usize) {
+                self.events.borrow_mut().push((kind, offset));
+            }
+        }
+
+        let indentation = Indentation::default();
+        let quote = Quote::default();
+        let line_ending = LineEnding::default();
+        let program = parser::parse_program("x = 1", "<filename>")?;
+        let stmt = program.first().unwrap();
+
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let mut generator = SourceCodeGenerator::new(&indentation, &quote, &line_ending)
+            .with_annotator(Box::new(Recorder {
+                events: events.clone(),
+            }));
+        generator.unparse_stmt(stmt);
+        assert_eq!(generator.generate()?, "x = 1");
+
+        let events = events.borrow();
+        // One statement and its two sub-expressions (target, value) were visited.
+        assert_eq!(events[0], (NodeKind::Statement, 0));
+        assert!(events
+            .iter()
+            .any(|(kind, _)| *kind == NodeKind::Expression));
+
+        Ok(())
+    }
+
     #[test]
     fn set_line_ending() -> Result<()> {
         assert_eq!(